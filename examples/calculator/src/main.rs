@@ -1,26 +1,12 @@
-use std::cell::RefCell;
 use std::io::{stdin, stdout, Write};
-use std::rc::Rc;
 
 use rusty_parser as rp;
 
 fn main() {
     use rp::*;
 
-    // dynamic Parser type
-    type DynParser = DynBoxChars<(i32,)>;
-
-    // initialize with dummy parser that always panic
-    let expr: Rc<RefCell<DynParser>> = Default::default();
-    let expr0: Rc<RefCell<DynParser>> = Default::default();
-    let expr1: Rc<RefCell<DynParser>> = Default::default();
-    let expr2: Rc<RefCell<DynParser>> = Default::default();
-
     /*
-    paren_expr: '(' expr ')'
-    expr0: num | paren_expr
-    expr1: expr0 ((*|/) expr0)*
-    expr2: expr1 ((+|-) expr1)*
+    expr: num | '(' expr ')' | expr (*|/) expr | expr (+|-) expr
     line_parser: expr lineend
     lineend: '\0'
      */
@@ -34,67 +20,36 @@ fn main() {
 
     // number [0-9]+
     // multiple digits -> build number
-    let num = digit.repeat(1..).map(|digits| -> i32 {
-        let mut res = 0;
-        for digit in digits {
-            res = res * 10 + digit;
-        }
-        res
-    });
-
-    // '(' expression ')'
-    let paren_expr = seq!(
-        '('.void(),
-        whitespaces,
-        Rc::clone(&expr),
-        whitespaces,
-        ')'.void()
-    );
-
-    // expr0: num | paren_expr
-    expr0.borrow_mut().assign(or!(num, paren_expr));
-
-    // expr1: expr0 ((*|/) expr0)*
-    let mul_or_div_op = or!('*', '/');
-    let mul_or_div = seq!(
-        Rc::clone(&expr0),
-        seq!(whitespaces, mul_or_div_op, whitespaces, Rc::clone(&expr0)).repeat(0..)
-    )
-    .map(|mut base, op_rhs_vec| -> i32 {
-        for (op, rhs) in op_rhs_vec {
-            if op == '*' {
-                base *= rhs;
-            } else {
-                base /= rhs;
+    let num = digit
+        .repeat(1..)
+        .try_map(|digits: Vec<i32>| -> Result<i32, &str> {
+            let mut res: i32 = 0;
+            for digit in digits {
+                res = res
+                    .checked_mul(10)
+                    .and_then(|res| res.checked_add(digit))
+                    .ok_or("number too large")?;
             }
-        }
-        base
-    });
-
-    expr1.borrow_mut().assign(mul_or_div);
-
-    // expr2: expr1 ((+|-) expr1)*
-    let add_or_sub_op = or!('+', '-');
-    let add_or_sub = seq!(
-        Rc::clone(&expr1),
-        seq!(whitespaces, add_or_sub_op, whitespaces, Rc::clone(&expr1)).repeat(0..)
-    )
-    .map(|mut base, op_rhs_vec| -> i32 {
-        for (op, rhs) in op_rhs_vec {
-            if op == '+' {
-                base += rhs;
-            } else {
-                base -= rhs;
-            }
-        }
-        base
-    });
-
-    expr2.borrow_mut().assign(add_or_sub);
-
-    expr.borrow_mut().assign(Rc::clone(&expr2));
-
-    let line_parser = seq!(whitespaces, Rc::clone(&expr), whitespaces, end());
+            Ok(res)
+        });
+
+    // operators by precedence; * and / bind tighter than + and -
+    let expr = pratt(num.padded(whitespaces))
+        .group('('.padded(whitespaces), ')'.padded(whitespaces))
+        .infix('*'.padded(whitespaces), Assoc::Left, 2, |lhs: i32, rhs| {
+            lhs * rhs
+        })
+        .infix('/'.padded(whitespaces), Assoc::Left, 2, |lhs: i32, rhs| {
+            lhs / rhs
+        })
+        .infix('+'.padded(whitespaces), Assoc::Left, 1, |lhs: i32, rhs| {
+            lhs + rhs
+        })
+        .infix('-'.padded(whitespaces), Assoc::Left, 1, |lhs: i32, rhs| {
+            lhs - rhs
+        });
+
+    let line_parser = seq!(expr, end());
 
     loop {
         print!("Enter a expression: ");
@@ -106,7 +61,13 @@ fn main() {
 
         match res.output {
             Some((res,)) => println!("Result: {}", res),
-            None => println!("Error"),
+            None => {
+                let error = res.error.unwrap();
+                let diagnostic = Diagnostic::new(&line, &error)
+                    .file_name("<stdin>")
+                    .color(true);
+                println!("{}", diagnostic);
+            }
         }
     }
 }
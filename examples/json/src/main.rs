@@ -1,4 +1,3 @@
-use std::cell::RefCell;
 use std::rc::Rc;
 use std::string::String;
 use std::vec::Vec;
@@ -30,13 +29,14 @@ fn string_parser() -> Rc<DynParser> {
         .map(|c: char| c as i32 - 'A' as i32 + 10);
     let hex = rp::or!(digit, hex_alpha_lower, hex_alpha_upper);
 
-    let unicode_char = rp::seq!('u'.void(), hex.repeat(4usize)).map(|hexs: Vec<i32>| -> char {
-        let mut res: u32 = 0;
-        for hex in hexs {
-            res = res * 16 + hex as u32;
-        }
-        char::from_u32(res).expect("invalid unicode character")
-    });
+    let unicode_char =
+        rp::seq!('u'.void(), hex.repeat(4usize)).try_map(|hexs: Vec<i32>| -> Result<char, &str> {
+            let mut res: u32 = 0;
+            for hex in hexs {
+                res = res * 16 + hex as u32;
+            }
+            char::from_u32(res).ok_or("invalid unicode character")
+        });
     let escape = rp::or!(
         '"',
         '\\',
@@ -46,18 +46,20 @@ fn string_parser() -> Rc<DynParser> {
         't'.output('\t'),
         unicode_char
     );
-    let escape = rp::seq!('\\'.void(), escape);
+    let escape = escape.preceded_by('\\');
     let character = ('\u{0020}'..='\u{10FFFF}').not('"').not('\\');
     let character = rp::or!(character, escape);
 
     let string = rp::seq!(
         '"'.void(),
+        rp::cut(),
         character
             .repeat(0..)
             .map(|chars: Vec<char>| -> String { chars.into_iter().collect::<String>() },),
         '"'.void()
     )
-    .map(|s: String| JsonValue::String(s));
+    .map(|s: String| JsonValue::String(s))
+    .label("string");
 
     Rc::new(DynParser::new(string))
 }
@@ -138,90 +140,69 @@ fn number_parser() -> DynParser {
         },
     );
 
-    DynParser::new(number)
+    DynParser::new(number.label("number"))
 }
 
-fn main() {
-    // init with dummy parser
-    let value: Rc<RefCell<rp::DynBoxChars<(JsonValue,)>>> = Default::default();
-    let object: Rc<RefCell<rp::DynBoxChars<(JsonValue,)>>> = Default::default();
-    let array: Rc<RefCell<rp::DynBoxChars<(JsonValue,)>>> = Default::default();
-
-    let true_ = "true".map(|| JsonValue::Bool(true));
-    let false_ = "false".map(|| JsonValue::Bool(false));
-    let bool_ = rp::or(true_, false_);
-
-    let null = "null".map(|| JsonValue::Null);
-
-    value.borrow_mut().assign(rp::or!(
-        null,
-        bool_,
-        number_parser(),
-        string_parser(),
-        std::rc::Rc::clone(&array),
-        std::rc::Rc::clone(&object)
-    ));
-
+fn json_parser() -> DynParser {
     let ws = rp::or!(' ', '\n', '\r', '\t').repeat(0..).void();
 
-    let element = rp::seq!(ws, std::rc::Rc::clone(&value), ws);
+    let value = rp::recursive(|value| {
+        let true_ = "true".map(|| JsonValue::Bool(true));
+        let false_ = "false".map(|| JsonValue::Bool(false));
+        let bool_ = rp::or(true_, false_);
 
-    let elements = rp::seq!(
-        element.clone(),
-        rp::seq!(','.void(), element.clone()).repeat(0..)
-    )
-    .map(|first: JsonValue, rest: Vec<JsonValue>| -> JsonValue {
-        let mut res = Vec::with_capacity(rest.len() + 1);
-        res.push(first);
-        for r in rest {
-            res.push(r);
-        }
-        JsonValue::Array(res)
-    });
+        let null = "null".map(|| JsonValue::Null);
 
-    array.borrow_mut().assign(rp::seq!(
-        '['.void(),
-        rp::or!(elements, ws.output(JsonValue::Array(Vec::new()))),
-        ']'.void()
-    ));
+        let element = value.padded(ws);
 
-    let member = rp::seq!(ws, string_parser(), ws, ':'.void(), element.clone());
+        let elements = element.clone().sep_by(',', 1..).map(JsonValue::Array);
 
-    let members = rp::seq!(
-        member.clone(),
-        rp::seq!(','.void(), member.clone()).repeat(0..)
-    )
-    .map(
-        |first_key: JsonValue,
-         first_value: JsonValue,
-         rest: Vec<(JsonValue, JsonValue)>|
-         -> JsonValue {
-            let mut res: HashMap<String, JsonValue> = HashMap::new();
-            match first_key {
-                JsonValue::String(first_key) => {
-                    res.insert(first_key, first_value);
-                }
-                _ => panic!("Key must be String type"),
-            }
-            for (key, value) in rest {
-                match key {
-                    JsonValue::String(key) => {
-                        res.insert(key, value);
+        let array = rp::or!(elements, ws.output(JsonValue::Array(Vec::new())))
+            .between('[', ']')
+            .label("array")
+            .context("in array");
+
+        let member =
+            rp::seq!(ws, string_parser(), ws, ':'.void(), element.clone()).context("in member");
+
+        let members =
+            member
+                .sep_by(',', 1..)
+                .map(|members: Vec<(JsonValue, JsonValue)>| -> JsonValue {
+                    let mut res: HashMap<String, JsonValue> = HashMap::new();
+                    for (key, value) in members {
+                        match key {
+                            JsonValue::String(key) => {
+                                res.insert(key, value);
+                            }
+                            _ => panic!("Key must be String type"),
+                        }
                     }
-                    _ => panic!("Key must be String type"),
-                }
-            }
-            JsonValue::Object(res)
-        },
-    );
+                    JsonValue::Object(res)
+                });
 
-    object.borrow_mut().assign(rp::seq!(
-        '{'.void(),
-        rp::or!(members, ws.output(JsonValue::Object(HashMap::new()))),
-        '}'.void()
-    ));
+        let object = rp::or!(members, ws.output(JsonValue::Object(HashMap::new())))
+            .between('{', '}')
+            .label("object")
+            .context("in object");
+
+        DynParser::new(rp::or!(
+            null,
+            bool_,
+            number_parser(),
+            string_parser(),
+            array,
+            object
+        ))
+    });
+
+    let element = value.padded(ws);
+
+    DynParser::new(rp::seq!(element, rp::end()))
+}
 
-    let json = rp::seq!(element, rp::end());
+fn main() {
+    let json = json_parser();
 
     loop {
         let mut line = String::new();
@@ -237,8 +218,39 @@ fn main() {
                 println!("{:?}", json_value);
             }
             None => {
-                println!("Failed to parse JSON");
+                let error = res.error.unwrap();
+                let diagnostic = rp::Diagnostic::new(&line, &error)
+                    .file_name("<stdin>")
+                    .color(true);
+                println!("{}", diagnostic);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn context(input: &str) -> String {
+        let res = rp::parse(&json_parser(), input.chars());
+        assert!(res.output.is_none());
+        let error = res.error.unwrap();
+        error
+            .context
+            .iter()
+            .rev()
+            .map(|context| context.as_ref())
+            .collect::<Vec<_>>()
+            .join(" → ")
+    }
+
+    #[test]
+    fn breadcrumbs() {
+        assert_eq!(context("[1,2,]"), "in array");
+        assert_eq!(
+            context(r#"{"a": [1, {"b": tru}]}"#),
+            "in object → in member → in array → in object → in member"
+        );
+    }
+}
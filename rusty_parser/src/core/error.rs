@@ -0,0 +1,460 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+use super::iterator_bound::InputIteratorTrait;
+use super::token::Token;
+
+/// Something a parser expected to see at the position it failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    /// An item or a sequence of items. e.g. `'a'`, `"hello"`
+    Token(Token),
+    /// Any item, from [`crate::any`].
+    Any,
+    /// End of input, from [`crate::end`].
+    End,
+    /// Human-readable name of sub-grammar, from [`crate::IntoParser::label`].
+    Label(Cow<'static, str>),
+    /// Error returned from the closure of [`crate::IntoParser::try_map`].
+    Message(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Token(token) => write!(f, "{}", token),
+            Expected::Any => write!(f, "any item"),
+            Expected::End => write!(f, "end of input"),
+            Expected::Label(label) => write!(f, "{}", label),
+            Expected::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// Set of [`Expected`] items of a [`ParseError`], in the order they were added.
+///
+/// A single item is kept inline; failing leaf parsers do not allocate.
+/// Dereferences to a slice of the items.
+#[derive(Clone, Default)]
+pub struct ExpectedSet(ExpectedItems);
+
+#[derive(Clone, Default)]
+enum ExpectedItems {
+    #[default]
+    Empty,
+    One(Expected),
+    Many(Vec<Expected>),
+}
+
+impl ExpectedSet {
+    /// Empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `expected` at the end of the set.
+    pub fn push(&mut self, expected: Expected) {
+        self.0 = match std::mem::take(&mut self.0) {
+            ExpectedItems::Empty => ExpectedItems::One(expected),
+            ExpectedItems::One(first) => {
+                // alternatives usually fail at the same position several at a time
+                let mut items = Vec::with_capacity(4);
+                items.push(first);
+                items.push(expected);
+                ExpectedItems::Many(items)
+            }
+            ExpectedItems::Many(mut items) => {
+                items.push(expected);
+                ExpectedItems::Many(items)
+            }
+        };
+    }
+
+    // items of `self`, then those of `other` not in `self`
+    fn union(self, other: Self) -> Self {
+        match (self.0, other.0) {
+            (ExpectedItems::Empty, items) | (items, ExpectedItems::Empty) => ExpectedSet(items),
+            // nested alternatives merge one item into the set of the rest; reuse its buffer
+            (ExpectedItems::One(first), ExpectedItems::Many(mut items)) => {
+                items.retain(|expected| *expected != first);
+                items.insert(0, first);
+                ExpectedSet(ExpectedItems::Many(items))
+            }
+            (lhs, rhs) => {
+                let mut merged = ExpectedSet(lhs);
+                for expected in ExpectedSet(rhs) {
+                    if !merged.contains(&expected) {
+                        merged.push(expected);
+                    }
+                }
+                merged
+            }
+        }
+    }
+}
+
+impl From<Expected> for ExpectedSet {
+    fn from(expected: Expected) -> Self {
+        ExpectedSet(ExpectedItems::One(expected))
+    }
+}
+
+impl From<Vec<Expected>> for ExpectedSet {
+    fn from(items: Vec<Expected>) -> Self {
+        ExpectedSet(ExpectedItems::Many(items))
+    }
+}
+
+impl std::ops::Deref for ExpectedSet {
+    type Target = [Expected];
+    fn deref(&self) -> &Self::Target {
+        match &self.0 {
+            ExpectedItems::Empty => &[],
+            ExpectedItems::One(expected) => std::slice::from_ref(expected),
+            ExpectedItems::Many(items) => items,
+        }
+    }
+}
+
+impl IntoIterator for ExpectedSet {
+    type Item = Expected;
+    type IntoIter = std::vec::IntoIter<Expected>;
+    fn into_iter(self) -> Self::IntoIter {
+        match self.0 {
+            ExpectedItems::Empty => Vec::new(),
+            ExpectedItems::One(expected) => vec![expected],
+            ExpectedItems::Many(items) => items,
+        }
+        .into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ExpectedSet {
+    type Item = &'a Expected;
+    type IntoIter = std::slice::Iter<'a, Expected>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl PartialEq for ExpectedSet {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+impl Eq for ExpectedSet {}
+
+impl PartialEq<Vec<Expected>> for ExpectedSet {
+    fn eq(&self, other: &Vec<Expected>) -> bool {
+        **self == **other
+    }
+}
+
+impl fmt::Debug for ExpectedSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Error describing why a parser failed.
+#[derive(Debug, Clone)]
+pub struct ParseError<It> {
+    /// Iterator at the position where the parser failed.
+    ///
+    /// This is not always the same as [`crate::ParseResult::it`],
+    /// since the result's iterator is rewound to where the failed parser started.
+    pub it: It,
+
+    /// Set of items expected at `it`.
+    ///
+    /// Empty if the failed parser cannot describe what it expected, e.g. [`crate::check`].
+    pub expected: ExpectedSet,
+
+    /// Context frames the error passed through, from [`crate::IntoParser::context`].
+    ///
+    /// Innermost first.
+    pub context: Vec<Cow<'static, str>>,
+
+    /// Error returned from the closure of [`crate::IntoParser::try_map`], with its type kept.
+    ///
+    /// Its message is also in `expected`, as [`Expected::Message`].
+    pub source: Option<Arc<dyn Error + Send + Sync>>,
+}
+
+impl<It> ParseError<It>
+where
+    It: InputIteratorTrait,
+{
+    /// Error at `it` without any expectation.
+    pub fn new(it: It) -> Self {
+        Self {
+            it,
+            expected: ExpectedSet::new(),
+            context: Vec::new(),
+            source: None,
+        }
+    }
+
+    /// Error at `it` expecting `expected`.
+    pub fn expected(it: It, expected: Expected) -> Self {
+        Self {
+            it,
+            expected: expected.into(),
+            context: Vec::new(),
+            source: None,
+        }
+    }
+
+    /// Error at `it` expecting `token`.
+    pub(crate) fn token(it: It, token: Token) -> Self {
+        Self {
+            it,
+            expected: Expected::Token(token).into(),
+            context: Vec::new(),
+            source: None,
+        }
+    }
+
+    /// Error at `it` rejected by `source`.
+    pub(crate) fn rejected(it: It, source: Box<dyn Error + Send + Sync>) -> Self {
+        Self {
+            it,
+            expected: Expected::Message(source.to_string()).into(),
+            context: Vec::new(),
+            source: Some(source.into()),
+        }
+    }
+
+    /// The item found at the failed position. `None` if the input was exhausted.
+    pub fn found(&self) -> Option<<It as Iterator>::Item> {
+        self.it.clone().next()
+    }
+
+    /// Check if this error is at the same position as `it`.
+    pub(crate) fn is_at(&self, it: &It) -> bool {
+        remaining(&self.it) == remaining(it)
+    }
+
+    /// Merge two errors into one.
+    ///
+    /// The error which reached further into the input is kept.
+    /// If both failed at the same position, their expected sets are merged,
+    /// and `self`'s context chain is kept, or `other`'s if `self` has none.
+    pub fn merge(self, other: Self) -> Self {
+        match (remaining(&self.it), remaining(&other.it)) {
+            (Some(lhs), Some(rhs)) if lhs < rhs => self,
+            (Some(lhs), Some(rhs)) if lhs > rhs => other,
+            _ => {
+                let mut merged = self;
+                merged.expected = merged.expected.union(other.expected);
+                if merged.context.is_empty() {
+                    merged.context = other.context;
+                }
+                merged.source = merged.source.or(other.source);
+                merged
+            }
+        }
+    }
+}
+
+/// merge two optional errors, see [`ParseError::merge`]
+pub(crate) fn merge<It>(
+    lhs: Option<ParseError<It>>,
+    rhs: Option<ParseError<It>>,
+) -> Option<ParseError<It>>
+where
+    It: InputIteratorTrait,
+{
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(lhs.merge(rhs)),
+        (lhs, None) => lhs,
+        (None, rhs) => rhs,
+    }
+}
+
+/// concatenate two lists of recovered errors, in order of occurrence
+pub(crate) fn concat<It>(
+    mut lhs: Vec<ParseError<It>>,
+    mut rhs: Vec<ParseError<It>>,
+) -> Vec<ParseError<It>> {
+    lhs.append(&mut rhs);
+    lhs
+}
+
+// number of items left in the iterator; smaller means further into the input.
+// upper bound of `size_hint` is exact or decreases with each item for std iterators
+// ( e.g. remaining bytes for `std::str::Chars` ).
+// `None` if the iterator cannot tell, then positions are considered equal.
+fn remaining<It: Iterator>(it: &It) -> Option<usize> {
+    it.size_hint().1
+}
+
+// whether `to` is further into the input than `from`; `true` if the iterator cannot tell.
+pub(crate) fn advanced<It: Iterator>(from: &It, to: &It) -> bool {
+    match (remaining(from), remaining(to)) {
+        (Some(from), Some(to)) => to < from,
+        _ => true,
+    }
+}
+
+impl<It> fmt::Display for ParseError<It> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // breadcrumbs from the outermost context
+        for (i, context) in self.context.iter().rev().enumerate() {
+            if i > 0 {
+                write!(f, " → ")?;
+            }
+            write!(f, "{}", context)?;
+        }
+        if !self.context.is_empty() {
+            write!(f, ": ")?;
+        }
+        write!(f, "{}", ExpectedMessage(&self.expected))
+    }
+}
+
+impl<It: fmt::Debug> Error for ParseError<It> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn Error + 'static))
+    }
+}
+
+/// "expected A, B or C" part of the error message
+pub(crate) struct ExpectedMessage<'a>(pub(crate) &'a [Expected]);
+
+impl fmt::Display for ExpectedMessage<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // messages from `try_map` are printed as is, before the expected set
+        let (messages, expected): (Vec<_>, Vec<_>) = self
+            .0
+            .iter()
+            .partition(|expected| matches!(expected, Expected::Message(_)));
+        if messages.is_empty() && expected.is_empty() {
+            return write!(f, "unexpected input");
+        }
+        for (i, message) in messages.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", message)?;
+        }
+        if expected.is_empty() {
+            return Ok(());
+        }
+        if !messages.is_empty() {
+            write!(f, "; ")?;
+        }
+        write!(f, "expected ")?;
+        let last = expected.len() - 1;
+        for (i, expected) in expected.iter().enumerate() {
+            if i > 0 {
+                if i == last {
+                    write!(f, " or ")?;
+                } else {
+                    write!(f, ", ")?;
+                }
+            }
+            write!(f, "{}", expected)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn merge_furthest() {
+        let input = "abcd";
+        let near = ParseError::expected(input.chars(), Expected::Token('x'.into()));
+        let mut it = input.chars();
+        it.next();
+        let far = ParseError::expected(it, Expected::Token('y'.into()));
+
+        let merged = near.clone().merge(far.clone());
+        assert_eq!(merged.it.as_str(), "bcd");
+        assert_eq!(merged.expected, vec![Expected::Token('y'.into())]);
+
+        let merged = far.merge(near);
+        assert_eq!(merged.it.as_str(), "bcd");
+    }
+
+    #[test]
+    fn merge_same_position() {
+        let lhs = ParseError::expected("abcd".chars(), Expected::Token(','.into()));
+        let rhs = ParseError::expected("abcd".chars(), Expected::Token(']'.into()));
+        let merged = lhs.clone().merge(rhs).merge(lhs);
+        assert_eq!(merged.found(), Some('a'));
+        assert_eq!(merged.to_string(), "expected ',' or ']'");
+    }
+
+    #[test]
+    fn expected_set() {
+        let mut set = ExpectedSet::new();
+        assert!(set.is_empty());
+        set.push(Expected::Any);
+        assert_eq!(set, vec![Expected::Any]);
+        set.push(Expected::End);
+        assert_eq!(set, vec![Expected::Any, Expected::End]);
+
+        // order is kept and duplicates are dropped
+        let set = ExpectedSet::from(Expected::End).union(set);
+        assert_eq!(set, vec![Expected::End, Expected::Any]);
+        let set = set.union(ExpectedSet::from(Expected::Any));
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            vec![Expected::End, Expected::Any]
+        );
+    }
+
+    #[test]
+    fn merge_context() {
+        let mut lhs = ParseError::expected("abcd".chars(), Expected::Token(','.into()));
+        lhs.context.push("in array".into());
+        let mut rhs = ParseError::expected("abcd".chars(), Expected::Token(':'.into()));
+        rhs.context.push("in member".into());
+        rhs.context.push("in object".into());
+
+        // the first trail is kept, not the longer one
+        let merged = lhs.clone().merge(rhs.clone());
+        assert_eq!(merged.context, vec!["in array"]);
+        let merged = rhs.merge(lhs.clone());
+        assert_eq!(merged.context, vec!["in member", "in object"]);
+
+        let other = ParseError::expected("abcd".chars(), Expected::Token(']'.into()));
+        let merged = other.merge(lhs);
+        assert_eq!(merged.context, vec!["in array"]);
+    }
+
+    #[test]
+    fn display() {
+        let error = ParseError::new("".chars());
+        assert_eq!(error.to_string(), "unexpected input");
+        assert_eq!(error.found(), None);
+
+        let mut error = ParseError::expected("".chars(), Expected::Token('a'.into()));
+        error.expected.push(Expected::Any);
+        error.expected.push(Expected::End);
+        assert_eq!(error.to_string(), "expected 'a', any item or end of input");
+
+        error.context.push("in member".into());
+        error.context.push("in object".into());
+        assert_eq!(
+            error.to_string(),
+            "in object → in member: expected 'a', any item or end of input"
+        );
+
+        let mut error = ParseError::expected(
+            "".chars(),
+            Expected::Message("number too large".to_string()),
+        );
+        assert_eq!(error.to_string(), "number too large");
+        error.expected.push(Expected::Token('a'.into()));
+        assert_eq!(error.to_string(), "number too large; expected 'a'");
+    }
+}
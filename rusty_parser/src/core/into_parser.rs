@@ -80,6 +80,122 @@ pub trait IntoParser {
         crate::wrapper::repeat::RepeatParser::from(self.into_parser(), range)
     }
 
+    /// repeat parser until `end` matches. Once the minimum count is reached, `end` is tried first at each step,
+    /// and the repetition stops when it matches; below the minimum only the parser is tried.
+    /// `range` is the number of repetitions before `end`; `end` is required after the maximum count.
+    ///
+    /// `Output`: `( *<Output of repeat>, *<Output of end> )`, where `Output of repeat` follows [`IntoParser::repeat`]
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// // everything up to "-->"
+    /// let comment = rp::seq!("<!--", rp::any().repeat_until("-->", 0..).string());
+    /// let res = rp::parse(&comment, "<!-- a -> b -->c".chars());
+    /// assert_eq!(res.output.unwrap(), (" a -> b -->".to_string(),));
+    /// assert_eq!(res.it.collect::<String>(), "c");
+    ///
+    /// let digits = ('0'..='9').repeat_until(';', 1..);
+    /// let res = rp::parse(&digits, "12;".chars());
+    /// assert_eq!(res.output.unwrap(), (vec!['1', '2'], ';'));
+    /// ```
+    fn repeat_until<EndParser: IntoParser, RangeTypeIncludeInteger>(
+        self,
+        end: EndParser,
+        range: RangeTypeIncludeInteger,
+    ) -> crate::wrapper::repeat_until::RepeatUntilParser<
+        Self::Into,
+        EndParser::Into,
+        RangeTypeIncludeInteger::Into,
+    >
+    where
+        Self: Sized,
+        RangeTypeIncludeInteger: crate::core::range_copyable::ToCopyable,
+        RangeTypeIncludeInteger::Into:
+            crate::core::range_copyable::RangeBound<crate::wrapper::repeat::RepeatCountType>,
+    {
+        crate::wrapper::repeat_until::RepeatUntilParser::from(
+            self.into_parser(),
+            end.into_parser(),
+            range,
+        )
+    }
+
+    /// repeat parser multiple times, separated by `sep`. This tries to match as long as possible.
+    /// `range` is the number of elements; output of the separators is discarded.
+    ///
+    /// Call `.trailing(`[`crate::Trailing`]`)` on it to allow or require a separator after the last element,
+    /// and `.with_separators()` to collect the output of the separators as `( *<Output>, *<Output of repeated sep> )`.
+    ///
+    /// `Output`:
+    ///  - if `Output` of the repeated parser is `()`, then `Output` is `()`
+    ///  - if `Output` of the repeated parser is `(T,)`, then `Output` is `(Vec<T>,)`
+    ///  - otherwise, `(Vec<Output of Self>,)`
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let digit = rp::range('0'..='9').map(|ch: char| ch.to_digit(10).unwrap());
+    /// let list = digit.sep_by(rp::seq!(' '.optional(), ','), 0..);
+    /// let res = rp::parse(&list, "1 ,2,3]".chars());
+    /// assert_eq!(res.output.unwrap(), (vec![1, 2, 3],));
+    /// assert_eq!(res.it.collect::<String>(), "]");
+    ///
+    /// let list = digit.sep_by(',', 0..).trailing(rp::Trailing::Allow);
+    /// let res = rp::parse(&list, "1,2,]".chars());
+    /// assert_eq!(res.output.unwrap(), (vec![1, 2],));
+    /// assert_eq!(res.it.collect::<String>(), "]");
+    /// ```
+    fn sep_by<SepParser: IntoParser, RangeTypeIncludeInteger>(
+        self,
+        sep: SepParser,
+        range: RangeTypeIncludeInteger,
+    ) -> crate::wrapper::sep_by::SepByParser<
+        Self::Into,
+        SepParser::Into,
+        RangeTypeIncludeInteger::Into,
+    >
+    where
+        Self: Sized,
+        RangeTypeIncludeInteger: crate::core::range_copyable::ToCopyable,
+        RangeTypeIncludeInteger::Into:
+            crate::core::range_copyable::RangeBound<crate::wrapper::repeat::RepeatCountType>,
+    {
+        crate::wrapper::sep_by::SepByParser::from(self.into_parser(), sep.into_parser(), range)
+    }
+
+    /// [`IntoParser::sep_by`] with at least one element.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let list = ('a'..='z').sep_by1('.');
+    /// let res = rp::parse(&list, "a.b.c".chars());
+    /// assert_eq!(res.output.unwrap(), (vec!['a', 'b', 'c'],));
+    ///
+    /// let res = rp::parse(&list, ".".chars());
+    /// assert!(res.output.is_none());
+    /// ```
+    fn sep_by1<SepParser: IntoParser>(
+        self,
+        sep: SepParser,
+    ) -> crate::wrapper::sep_by::SepByParser<
+        Self::Into,
+        SepParser::Into,
+        crate::core::range_copyable::RangeFrom<crate::wrapper::repeat::RepeatCountType>,
+    >
+    where
+        Self: Sized,
+    {
+        crate::wrapper::sep_by::SepByParser::from(self.into_parser(), sep.into_parser(), 1..)
+    }
+
     /// or combinator for two parsers
     ///
     /// `Output`: `Output` of the all parsers.
@@ -150,6 +266,120 @@ pub trait IntoParser {
         crate::wrapper::map::MapParser::new(self.into_parser(), callback)
     }
 
+    /// Build the parser for the rest of the input from parser's Output; context-sensitive parsing.
+    ///
+    /// Parser's Output will be unpacked and passed to the closure, which returns a new parser.
+    /// The new parser runs on the rest of the input, and its Output will be new Output.
+    /// If either parser fails, this fails at the position it started.
+    ///
+    /// Since the closure needs the Output, `match_pattern` parses the first parser fully.
+    ///
+    /// `Output`: `Output` of the parser returned from the closure
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// // count N, then exactly N items
+    /// let count = rp::range('0'..='9').map(|ch: char| ch.to_digit(10).unwrap() as usize);
+    /// let items = count.and_then(|count| rp::any().repeat(count));
+    ///
+    /// let res = rp::parse(&items, "3abcde".chars());
+    /// assert_eq!(res.output.unwrap(), (vec!['a', 'b', 'c'],));
+    /// assert_eq!(res.it.collect::<String>(), "de");
+    ///
+    /// let res = rp::parse(&items, "3ab".chars());
+    /// assert!(res.output.is_none());
+    /// ```
+    fn and_then<ClosureType>(
+        self,
+        callback: ClosureType,
+    ) -> crate::wrapper::and_then::AndThenParser<Self::Into, ClosureType>
+    where
+        Self: Sized,
+    {
+        crate::wrapper::and_then::AndThenParser::new(self.into_parser(), callback)
+    }
+
+    /// Map parser's Output to new value with a fallible closure.
+    ///
+    /// Parser's Output will be unpacked and passed to the closure, which returns `Result<T, E>` or `Option<T>`.
+    /// On `Ok`, the value will be new Output.
+    /// On `Err` or `None`, the match is rejected and the parser fails at the position it started;
+    /// the error `E` is kept in [`crate::ParseError::source`], and its message is reported as [`crate::Expected::Message`].
+    /// `E` is any [`std::error::Error`] type, `String` or `&str`.
+    ///
+    /// `Output`: `(T,)` where `T` is the `Ok` type of the closure's return value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let digits = ('0'..='9').repeat(1..);
+    /// let byte_parser = digits.try_map(|digits: Vec<char>| {
+    ///     digits.into_iter().collect::<String>().parse::<u8>()
+    /// });
+    ///
+    /// let res = rp::parse(&byte_parser, "123".chars());
+    /// assert_eq!(res.output.unwrap(), (123,));
+    ///
+    /// let res = rp::parse(&byte_parser, "1234".chars());
+    /// assert_eq!(res.output, None);
+    /// assert_eq!(res.it.collect::<String>(), "1234");
+    /// let error = res.error.unwrap();
+    /// assert_eq!(error.to_string(), "number too large to fit in target type");
+    /// assert!(error.source.unwrap().is::<std::num::ParseIntError>());
+    /// ```
+    fn try_map<ClosureType>(
+        self,
+        callback: ClosureType,
+    ) -> crate::wrapper::try_map::TryMapParser<Self::Into, ClosureType>
+    where
+        Self: Sized,
+    {
+        crate::wrapper::try_map::TryMapParser::new(self.into_parser(), callback)
+    }
+
+    /// Check parser's Output with a predicate.
+    ///
+    /// References to the values of parser's Output are passed to the predicate.
+    /// If it returns `false`, the match is rejected and the parser fails at the position it started;
+    /// the error is reported at that position, instead of the error of the parser;
+    /// name what is expected there with [`IntoParser::label`].
+    /// Since the predicate needs the Output, `match_pattern(...)` builds the Output too.
+    ///
+    /// `Output`: `Output` of `self`
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let ident = ('a'..='z').repeat(1..).string();
+    /// let not_keyword = ident
+    ///     .verify(|ident: &String| ident != "let" && ident != "fn")
+    ///     .label("identifier, not a keyword");
+    ///
+    /// let res = rp::parse(&not_keyword, "name".chars());
+    /// assert_eq!(res.output.unwrap(), ("name".to_string(),));
+    ///
+    /// let res = rp::parse(&not_keyword, "let x".chars());
+    /// assert_eq!(res.output, None);
+    /// assert_eq!(res.it.collect::<String>(), "let x");
+    /// assert_eq!(res.error.unwrap().to_string(), "expected identifier, not a keyword");
+    /// ```
+    fn verify<ClosureType>(
+        self,
+        predicate: ClosureType,
+    ) -> crate::wrapper::verify::VerifyParser<Self::Into, ClosureType>
+    where
+        Self: Sized,
+    {
+        crate::wrapper::verify::VerifyParser::new(self.into_parser(), predicate)
+    }
+
     /// Change Parser's Output to `()`.
     /// This internally call `crate::match_pattern()` instead of `crate::parse()`
     ///
@@ -179,6 +409,118 @@ pub trait IntoParser {
         crate::wrapper::void::VoidParser::new(self.into_parser())
     }
 
+    /// Match `open`, this parser, then `close`; `Output` of `open` and `close` is discarded.
+    ///
+    /// `Output`: `Output` of Self
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let paren_parser = ('0'..='9').between('(', ')');
+    /// let res = rp::parse(&paren_parser, "(1)".chars());
+    /// assert_eq!(res.output.unwrap(), ('1',));
+    /// ```
+    fn between<OpenParser: IntoParser, CloseParser: IntoParser>(
+        self,
+        open: OpenParser,
+        close: CloseParser,
+    ) -> crate::wrapper::delimited::DelimitedParser<OpenParser::Into, Self::Into, CloseParser::Into>
+    where
+        Self: Sized,
+    {
+        crate::wrapper::seq::SeqParser::new(
+            crate::wrapper::seq::SeqParser::new(open.void(), self.into_parser()),
+            close.void(),
+        )
+    }
+
+    /// Match `prefix`, then this parser; `Output` of `prefix` is discarded.
+    ///
+    /// `Output`: `Output` of Self
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let negative_parser = ('0'..='9').preceded_by('-');
+    /// let res = rp::parse(&negative_parser, "-1".chars());
+    /// assert_eq!(res.output.unwrap(), ('1',));
+    /// ```
+    fn preceded_by<PrefixParser: IntoParser>(
+        self,
+        prefix: PrefixParser,
+    ) -> crate::wrapper::seq::SeqParser<
+        crate::wrapper::void::VoidParser<PrefixParser::Into>,
+        Self::Into,
+    >
+    where
+        Self: Sized,
+    {
+        crate::wrapper::seq::SeqParser::new(prefix.void(), self.into_parser())
+    }
+
+    /// Match this parser, then `suffix`; `Output` of `suffix` is discarded.
+    ///
+    /// `Output`: `Output` of Self
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let statement_parser = ('a'..='z').repeat(1..).terminated_by(';');
+    /// let res = rp::parse(&statement_parser, "abc;".chars());
+    /// assert_eq!(res.output.unwrap(), (vec!['a', 'b', 'c'],));
+    /// ```
+    fn terminated_by<SuffixParser: IntoParser>(
+        self,
+        suffix: SuffixParser,
+    ) -> crate::wrapper::seq::SeqParser<
+        Self::Into,
+        crate::wrapper::void::VoidParser<SuffixParser::Into>,
+    >
+    where
+        Self: Sized,
+    {
+        crate::wrapper::seq::SeqParser::new(self.into_parser(), suffix.void())
+    }
+
+    /// Match `ws` on both sides of this parser; `Output` of `ws` is discarded.
+    ///
+    /// `Output`: `Output` of Self
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let ws = ' '.repeat(0..);
+    /// let padded_parser = ('0'..='9').padded(ws);
+    /// let res = rp::parse(&padded_parser, "  1 ,".chars());
+    /// assert_eq!(res.output.unwrap(), ('1',));
+    /// assert_eq!(res.it.collect::<String>(), ",");
+    /// ```
+    fn padded<WsParser: IntoParser>(
+        self,
+        ws: WsParser,
+    ) -> crate::wrapper::delimited::DelimitedParser<WsParser::Into, Self::Into, WsParser::Into>
+    where
+        Self: Sized,
+        WsParser::Into: Clone,
+    {
+        let ws = ws.into_parser();
+        crate::wrapper::seq::SeqParser::new(
+            crate::wrapper::seq::SeqParser::new(
+                crate::wrapper::void::VoidParser::new(ws.clone()),
+                self.into_parser(),
+            ),
+            crate::wrapper::void::VoidParser::new(ws),
+        )
+    }
+
     /// This parser always success whether the input is matched or not.
     ///
     /// `Output`:
@@ -322,7 +664,7 @@ pub trait IntoParser {
     }
 
     /// Returns String of parsed input.
-    /// Only works for parsing with [`std::str::Chars`].
+    /// Only works for parsing with input implementing [`crate::SliceInput`] over `&str`; [`std::str::Chars`] and [`crate::Located`] of it.
     ///
     /// `Output`: `(String,)`
     ///
@@ -346,6 +688,32 @@ pub trait IntoParser {
         crate::wrapper::slice::StringParser::new(self.into_parser())
     }
 
+    /// Returns `&str` of parsed input, borrowed from the input string.
+    /// Only works for parsing with input implementing [`crate::SliceInput`] over `&str`; [`std::str::Chars`] and [`crate::Located`] of it.
+    ///
+    /// Same as [`IntoParser::string`], but without allocating a new `String`.
+    ///
+    /// `Output`: `(&str,)`
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let digits_parser = ('0'..='9').repeat(0..).slice_str();
+    ///
+    /// let res = rp::parse(&digits_parser, "123456hello_world".chars());
+    /// assert_eq!(res.output.unwrap(), ("123456",));
+    /// assert_eq!(res.it.collect::<String>(), "hello_world");
+    /// ```
+    fn slice_str(self) -> crate::wrapper::slice::StrParser<Self::Into>
+    where
+        Self: Sized,
+        Self::Into: for<'a> crate::core::parser::Parser<std::str::Chars<'a>>,
+    {
+        crate::wrapper::slice::StrParser::new(self.into_parser())
+    }
+
     /// Returns `Vec\<T\>` of parsed input.
     /// Only works for parsing with [`ExactSizeIterator`].
     ///
@@ -370,6 +738,33 @@ pub trait IntoParser {
         crate::wrapper::slice::VecParser::new(self.into_parser())
     }
 
+    /// Returns the parsed input, borrowed from the input buffer.
+    /// Only works for parsing with input implementing [`crate::SliceInput`];
+    /// [`std::slice::Iter`] and [`crate::SliceCloned`] ( `&[T]` ), and [`std::str::Chars`] ( `&str` ).
+    ///
+    /// Same as [`IntoParser::vec`], but without copying into a new `Vec`.
+    ///
+    /// `Output`: `(&[T],)` or `(&str,)`
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let digits_parser = (&b'0'..=&b'9').repeat(1..).slice_ref();
+    ///
+    /// let input = b"1234hello";
+    /// let res = rp::parse(&digits_parser, input.iter());
+    /// assert_eq!(res.output.unwrap(), (&input[..4],));
+    /// assert_eq!(res.it.as_slice(), b"hello");
+    /// ```
+    fn slice_ref(self) -> crate::wrapper::slice::SliceRefParser<Self::Into>
+    where
+        Self: Sized,
+    {
+        crate::wrapper::slice::SliceRefParser::new(self.into_parser())
+    }
+
     /// Parser will not consume the input iterator.
     /// It still matches and return the output.
     ///
@@ -588,4 +983,178 @@ pub trait IntoParser {
     {
         crate::wrapper::inspect::InspectParser::new(self.into_parser(), closure)
     }
+
+    /// Name this parser for error reporting.
+    ///
+    /// If this parser failed without consuming any input,
+    /// the error will say `expected <label>` instead of listing every item expected inside.
+    /// The label also shows through parsers discarding the output, e.g. [`IntoParser::void`].
+    ///
+    /// The label may be an owned `String`, so the returned parser is `Clone` but not `Copy`;
+    /// clone it to use it more than once.
+    ///
+    /// `Output`: `Output` of `Self`
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let number = ('0'..='9').repeat(1..).string().label("number");
+    /// let pair = rp::seq!(number.clone(), ',', number.clone());
+    ///
+    /// let res = rp::parse(&pair, "12,x".chars());
+    /// assert_eq!(res.output, None);
+    /// assert_eq!(res.error.unwrap().to_string(), "expected number");
+    ///
+    /// let group = rp::seq!('(', number, ')').void();
+    /// let res = rp::parse(&group, "(x)".chars());
+    /// assert_eq!(res.error.unwrap().to_string(), "expected number");
+    /// ```
+    fn label<Label>(self, label: Label) -> crate::wrapper::label::LabelParser<Self::Into>
+    where
+        Self: Sized,
+        Label: Into<std::borrow::Cow<'static, str>>,
+    {
+        crate::wrapper::label::LabelParser::new(self.into_parser(), label.into())
+    }
+
+    /// Add a context frame to the error reported from inside of this parser, when it fails.
+    ///
+    /// Nested contexts build a breadcrumb trail in [`crate::ParseError::context`],
+    /// from the failing parser to the outermost one.
+    /// No frame is added if this parser failed at its start, e.g. as an alternative not taken.
+    ///
+    /// Like [`IntoParser::label`], the returned parser is `Clone` but not `Copy`.
+    ///
+    /// `Output`: `Output` of `Self`
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let member = rp::seq!('k', ':', ('0'..='9')).context("in member");
+    /// let object = rp::seq!('{', member, '}').context("in object");
+    ///
+    /// let res = rp::parse(&object, "{k=1}".chars());
+    /// assert_eq!(res.output, None);
+    /// assert_eq!(
+    ///     res.error.unwrap().to_string(),
+    ///     "in object → in member: expected ':'"
+    /// );
+    /// ```
+    fn context<Context>(
+        self,
+        context: Context,
+    ) -> crate::wrapper::context::ContextParser<Self::Into>
+    where
+        Self: Sized,
+        Context: Into<std::borrow::Cow<'static, str>>,
+    {
+        crate::wrapper::context::ContextParser::new(self.into_parser(), context.into())
+    }
+
+    /// If this parser failed, record the error and parse `recovery` from the same position instead.
+    ///
+    /// The error is pushed to [`crate::ParseResult::recovered`],
+    /// and the `Output` of `recovery` is used as a placeholder.
+    /// `recovery` matching without consuming any input counts as a failure,
+    /// so this can be repeated without looping forever.
+    /// Use with [`crate::skip_until`] or [`crate::recover_between`] to resynchronize the input.
+    ///
+    /// `Output`: `Output` of `Self`
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// // stmt: digit ';'
+    /// let stmt = rp::seq!(('0'..='9').label("digit"), ';'.void());
+    /// // on failure, skip until ';' and use '?' as placeholder
+    /// let stmt = stmt.recover_with(rp::skip_until(';').output('?'));
+    /// let stmts = stmt.repeat(0..);
+    ///
+    /// let res = rp::parse(&stmts, "1;x;2;3y;".chars());
+    /// assert_eq!(res.output.unwrap(), (vec!['1', '?', '2', '?'],));
+    ///
+    /// assert_eq!(res.recovered.len(), 2);
+    /// assert_eq!(res.recovered[0].to_string(), "expected digit");
+    /// assert_eq!(res.recovered[1].to_string(), "expected ';'");
+    /// ```
+    fn recover_with<RecoveryParser: IntoParser>(
+        self,
+        recovery: RecoveryParser,
+    ) -> crate::wrapper::recover::RecoverParser<Self::Into, RecoveryParser::Into>
+    where
+        Self: Sized,
+    {
+        crate::wrapper::recover::RecoverParser::new(self.into_parser(), recovery.into_parser())
+    }
+
+    /// Append the [`crate::Span`] of the input matched by this parser to the output.
+    ///
+    /// Only works for parsing with input implementing [`crate::SpanInput`].
+    /// [`crate::Located`] ( byte offsets ), the reader inputs and [`crate::SliceCloned`] ( indices )
+    /// give offsets from the start of the input.
+    /// Plain `Chars` ( bytes ), slice iterators and `Copied` / `Cloned` of them ( items )
+    /// only know the length of the input left, so their spans are counted from the end of the input;
+    /// resolve them against the whole input with [`crate::Span::range_in`].
+    ///
+    /// `Output`: `( *<Output of Self>, Span )`
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// let ident = ('a'..='z').repeat(1..).string().spanned();
+    /// let parser = rp::seq!(' '.repeat(0..).void(), ident);
+    ///
+    /// let input = "  hello world";
+    /// let res = rp::parse(&parser, rp::Located::new(input.chars()));
+    /// let (ident, span) = res.output.unwrap();
+    /// assert_eq!(ident, "hello");
+    /// assert_eq!(span.range(), Some(2..7));
+    ///
+    /// let res = rp::parse(&parser, input.chars());
+    /// let (_, span) = res.output.unwrap();
+    /// assert_eq!(span.range(), None);
+    /// assert_eq!(span.range_in(input), 2..7);
+    /// ```
+    fn spanned(self) -> crate::wrapper::spanned::SpannedParser<Self::Into>
+    where
+        Self: Sized,
+    {
+        crate::wrapper::spanned::SpannedParser::new(self.into_parser())
+    }
+
+    /// Append the parsed input, borrowed from the input buffer, to the output.
+    /// Only works for parsing with input implementing [`crate::SliceInput`];
+    /// [`std::slice::Iter`] and [`crate::SliceCloned`] ( `&[T]` ), and [`std::str::Chars`] ( `&str` ).
+    ///
+    /// `Output`: `( *<Output of Self>, &[T] )` or `( *<Output of Self>, &str )`
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusty_parser as rp;
+    /// use rp::IntoParser;
+    ///
+    /// // sign byte, then digits
+    /// let number = rp::seq!(rp::any(), (&b'0'..=&b'9').repeat(1..)).recognize();
+    ///
+    /// let input = b"-123;";
+    /// let res = rp::parse(&number, input.iter());
+    /// let (sign, digits, raw) = res.output.unwrap();
+    /// assert_eq!(*sign, b'-');
+    /// assert_eq!(digits.len(), 3);
+    /// assert_eq!(raw, b"-123");
+    /// ```
+    fn recognize(self) -> crate::wrapper::recognize::RecognizeParser<Self::Into>
+    where
+        Self: Sized,
+    {
+        crate::wrapper::recognize::RecognizeParser::new(self.into_parser())
+    }
 }
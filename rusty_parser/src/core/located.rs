@@ -0,0 +1,196 @@
+//! Input iterator adapter that tracks the location of the items consumed.
+
+/// Location in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location {
+    /// Offset in bytes from the start of the input.
+    pub offset: usize,
+    /// Number of items consumed from the start of the input; chars for `Chars`.
+    pub index: usize,
+    /// Line number, starting from 1.
+    pub line: usize,
+    /// Column number in items, starting from 1.
+    pub column: usize,
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            index: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+}
+
+/// Item of the input which [`Located`] can track.
+pub trait LocatedItem {
+    /// Length of this item in bytes.
+    fn byte_len(&self) -> usize;
+    /// Whether this item starts a new line.
+    fn is_newline(&self) -> bool;
+}
+
+impl LocatedItem for char {
+    fn byte_len(&self) -> usize {
+        self.len_utf8()
+    }
+    fn is_newline(&self) -> bool {
+        *self == '\n'
+    }
+}
+impl LocatedItem for u8 {
+    fn byte_len(&self) -> usize {
+        1
+    }
+    fn is_newline(&self) -> bool {
+        *self == b'\n'
+    }
+}
+impl LocatedItem for &u8 {
+    fn byte_len(&self) -> usize {
+        1
+    }
+    fn is_newline(&self) -> bool {
+        **self == b'\n'
+    }
+}
+
+/// Input iterator adapter that tracks the [`Location`] of the items consumed.
+#[derive(Debug, Clone)]
+pub struct Located<It> {
+    it: It,
+    location: Location,
+}
+
+impl<It> Located<It> {
+    pub fn new(it: It) -> Self {
+        Self {
+            it,
+            location: Location::default(),
+        }
+    }
+
+    /// Location of the next item.
+    pub fn location(&self) -> Location {
+        self.location
+    }
+
+    /// The wrapped iterator.
+    pub fn inner(&self) -> &It {
+        &self.it
+    }
+
+    /// Unwrap the wrapped iterator.
+    pub fn into_inner(self) -> It {
+        self.it
+    }
+}
+
+impl<'a> Located<std::str::Chars<'a>> {
+    /// Remaining string, same as [`std::str::Chars::as_str`].
+    pub fn as_str(&self) -> &'a str {
+        self.it.as_str()
+    }
+}
+
+impl<It> Iterator for Located<It>
+where
+    It: Iterator,
+    It::Item: LocatedItem,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.it.next()?;
+        self.location.offset += item.byte_len();
+        self.location.index += 1;
+        if item.is_newline() {
+            self.location.line += 1;
+            self.location.column = 1;
+        } else {
+            self.location.column += 1;
+        }
+        Some(item)
+    }
+
+    // used for comparing positions of errors
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+}
+
+impl<It> ExactSizeIterator for Located<It>
+where
+    It: ExactSizeIterator,
+    It::Item: LocatedItem,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::leaf::stringeq::StrEqualParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::Parser;
+
+    #[test]
+    fn location() {
+        let mut it = Located::new("a\nbé\nc".chars());
+        assert_eq!(it.location(), Location::default());
+
+        it.next();
+        it.next();
+        assert_eq!(
+            it.location(),
+            Location {
+                offset: 2,
+                index: 2,
+                line: 2,
+                column: 1
+            }
+        );
+
+        it.next();
+        it.next();
+        assert_eq!(
+            it.location(),
+            Location {
+                offset: 5,
+                index: 4,
+                line: 2,
+                column: 3
+            }
+        );
+        assert_eq!(it.as_str(), "\nc");
+    }
+
+    #[test]
+    fn parse() {
+        let parser = SeqParser::new(StrEqualParser::new("ab\n"), SingleEqualParser::new('c'));
+
+        let res = parser.parse(Located::new("ab\ncd".chars()));
+        assert_eq!(res.output, Some(('c',)));
+        assert_eq!(res.it.location().line, 2);
+        assert_eq!(res.it.location().column, 2);
+
+        let res = parser.parse(Located::new("ab\nxd".chars()));
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.location(), Location::default());
+        let location = res.error.unwrap().it.location();
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 1);
+        assert_eq!(location.offset, 3);
+    }
+
+    #[test]
+    fn bytes() {
+        let parser = SingleEqualParser::new(b'\n');
+        let res = parser.parse(Located::new(b"\nx".iter().copied()));
+        assert_eq!(res.output, Some((b'\n',)));
+        assert_eq!(res.it.location().line, 2);
+        assert_eq!(res.it.len(), 1);
+    }
+}
@@ -1,6 +1,13 @@
+pub mod error;
 pub mod into_parser;
 pub mod iterator_bound;
+pub mod located;
 pub mod parser;
+pub mod partial;
 pub mod range_copyable;
+pub mod read;
 pub mod result;
+pub mod slice_cloned;
+pub mod span;
+pub mod token;
 pub mod tuple;
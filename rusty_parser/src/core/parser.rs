@@ -16,17 +16,21 @@ where
     // for some parser, there may be a cheaper way to check if it matches the pattern
     // than actually parsing the data
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
-        let res = self.parse(it);
-        if res.output.is_some() {
-            ParseResult {
-                output: Some(()),
-                it: res.it,
-            }
-        } else {
-            ParseResult {
-                output: None,
-                it: res.it,
-            }
-        }
+        self.parse(it).map_output(|_| ())
+    }
+}
+
+// match `parser` for a combinator which discards its output in `parse`;
+// leaf parsers do not build errors under `match_pattern`, so a failure is parsed again to report one
+pub(crate) fn match_reporting<ParserType, It>(parser: &ParserType, it: It) -> ParseResult<(), It>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It> + ?Sized,
+{
+    let res = parser.match_pattern(it.clone());
+    if res.output.is_some() {
+        res
+    } else {
+        parser.parse(it).map_output(|_| ())
     }
 }
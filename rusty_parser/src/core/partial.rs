@@ -0,0 +1,111 @@
+//! Partial input, for input that arrives in chunks.
+//!
+//! A leaf parser which runs out of input fails as usual,
+//! and reports [`ParseResult::incomplete`] with the number of items it was short of matching.
+//! Combinators propagate it from every branch they tried, so parsing itself is the same for
+//! complete and partial input; [`crate::parse_partial()`] only reads the result differently.
+
+use super::error::ParseError;
+use super::iterator_bound::InputIteratorTrait;
+use super::result::ParseResult;
+use super::tuple::Tuple;
+
+/// Parsing stopped at the end of the partial input; append more data and parse again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Incomplete {
+    /// Minimum number of items needed to make progress.
+    pub needed: usize,
+}
+
+/// Result of a leaf parser which started at `i0` and ran out of input,
+/// `needed` items short of matching; `error` is `None` under `match_pattern`.
+pub(crate) fn exhausted<Output, It>(
+    i0: It,
+    needed: usize,
+    error: Option<ParseError<It>>,
+) -> ParseResult<Output, It>
+where
+    Output: Tuple,
+    It: InputIteratorTrait,
+{
+    ParseResult {
+        error,
+        incomplete: Some(Incomplete { needed }),
+        ..ParseResult::unmatched(i0)
+    }
+}
+
+/// merge the incomplete states of two attempts; the smaller `needed` is kept.
+pub(crate) fn merge(lhs: Option<Incomplete>, rhs: Option<Incomplete>) -> Option<Incomplete> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(Incomplete {
+            needed: lhs.needed.min(rhs.needed),
+        }),
+        (lhs, rhs) => lhs.or(rhs),
+    }
+}
+
+/// Result of parsing partial input; `Err` if any parser ran out of input.
+pub(crate) fn finish<Output, It>(
+    res: ParseResult<Output, It>,
+) -> Result<ParseResult<Output, It>, Incomplete>
+where
+    Output: Tuple,
+    It: InputIteratorTrait,
+{
+    match res.incomplete {
+        Some(incomplete) => Err(incomplete),
+        None => Ok(res),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn exhausted_is_plain_failure() {
+        let res: ParseResult<(), _> =
+            exhausted("ab".chars(), 2, Some(ParseError::new("ab".chars())));
+        assert!(!res.committed);
+        assert_eq!(res.incomplete, Some(Incomplete { needed: 2 }));
+        assert_eq!(res.error.unwrap().it.as_str(), "ab");
+    }
+
+    #[test]
+    fn merge_min() {
+        let two = Some(Incomplete { needed: 2 });
+        let one = Some(Incomplete { needed: 1 });
+        assert_eq!(merge(two, one), one);
+        assert_eq!(merge(None, two), two);
+        assert_eq!(merge(None, None), None);
+    }
+
+    #[test]
+    fn not_committed_by_cut() {
+        use crate::IntoParser;
+
+        // "ab" is short of "abc" before the cut; the other branch is still tried
+        let parser = crate::or!(crate::seq!("abc", crate::cut(), 'd').void(), "ab");
+        let res = crate::parse(&parser, "ab".chars());
+        assert_eq!(res.output, Some(()));
+        assert!(!res.committed);
+        assert_eq!(res.incomplete, Some(Incomplete { needed: 1 }));
+
+        let res = crate::parse_partial(&parser, "ab".chars());
+        assert_eq!(res.unwrap_err(), Incomplete { needed: 1 });
+    }
+
+    #[test]
+    fn nested_parse() {
+        use crate::IntoParser;
+
+        // a complete parse inside a partial one is not affected by it
+        let parser = crate::one('a').map(|_| {
+            let inner = crate::parse(&crate::seq!('b', crate::end()), "b".chars());
+            inner.output.is_some()
+        });
+        let res = crate::parse_partial(&parser, "a;".chars()).unwrap();
+        assert_eq!(res.output, Some((true,)));
+    }
+}
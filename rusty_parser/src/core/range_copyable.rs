@@ -1,6 +1,8 @@
-/// Defined new RangeBound types.
-/// They are clones of std::ops::Range, std::ops::RangeFrom, std::ops::RangeTo, std::ops::RangeFull, std::ops::RangeInclusive, std::ops::RangeToInclusive.
-/// but implemements `Copy` trait.
+//! Defined new RangeBound types.
+//! They are clones of std::ops::Range, std::ops::RangeFrom, std::ops::RangeTo, std::ops::RangeFull, std::ops::RangeInclusive, std::ops::RangeToInclusive.
+//! but implemements `Copy` trait.
+
+use std::fmt;
 
 pub trait ToCopyable {
     type Into: Clone + Copy;
@@ -11,7 +13,7 @@ pub trait RangeBound<Idx>: Clone + Copy {
     fn contains(&self, count: &Idx) -> bool;
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct Range<Idx>
 where
     Idx: Clone + Copy,
@@ -20,7 +22,7 @@ where
     pub end: Idx,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct RangeFrom<Idx>
 where
     Idx: Clone + Copy,
@@ -28,7 +30,7 @@ where
     pub start: Idx,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct RangeTo<Idx>
 where
     Idx: Clone + Copy,
@@ -36,10 +38,10 @@ where
     pub end: Idx,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct RangeFull;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct RangeInclusive<Idx>
 where
     Idx: Clone + Copy,
@@ -48,7 +50,7 @@ where
     pub end: Idx,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct RangeToInclusive<Idx>
 where
     Idx: Clone + Copy,
@@ -56,6 +58,58 @@ where
     pub end: Idx,
 }
 
+// Debug formats the same as std::ops ranges, e.g. `'0'..='9'`
+impl<Idx> fmt::Debug for Range<Idx>
+where
+    Idx: Clone + Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+impl<Idx> fmt::Debug for RangeFrom<Idx>
+where
+    Idx: Clone + Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..", self.start)
+    }
+}
+
+impl<Idx> fmt::Debug for RangeTo<Idx>
+where
+    Idx: Clone + Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "..{:?}", self.end)
+    }
+}
+
+impl fmt::Debug for RangeFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "..")
+    }
+}
+
+impl<Idx> fmt::Debug for RangeInclusive<Idx>
+where
+    Idx: Clone + Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..={:?}", self.start, self.end)
+    }
+}
+
+impl<Idx> fmt::Debug for RangeToInclusive<Idx>
+where
+    Idx: Clone + Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "..={:?}", self.end)
+    }
+}
+
 impl<Idx> ToCopyable for std::ops::Range<Idx>
 where
     Idx: Clone + Copy,
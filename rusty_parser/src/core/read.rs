@@ -0,0 +1,379 @@
+//! Input iterators reading from [`std::io::Read`], with a shared buffer.
+//!
+//! The buffer is a chain of chunks, read on demand and shared by all clones of the input.
+//! A clone is a cursor into the chain; chunks before the oldest live cursor are dropped.
+//!
+//! Parsers keep the cursor they started from until they return,
+//! so a single `parse` call holds all the data from where it started.
+//! To parse a long stream with bounded memory, parse it piece by piece,
+//! one top-level item per call, continuing from [`crate::ParseResult::it`];
+//! only the data of the item being parsed is kept.
+//!
+//! A [`crate::ParseError`] holds a cursor too, and so does a result holding one,
+//! including the furthest error kept in [`crate::ParseResult::error`] after a successful parse.
+//! It keeps the chunks from its position alive; when parsing a long stream piece by piece,
+//! keep only [`ReadBytes::offset`] of the error and drop it.
+
+use std::cell::{OnceCell, RefCell};
+use std::fmt;
+use std::io::{self, Read};
+use std::rc::Rc;
+
+const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+struct Chunk {
+    data: Vec<u8>,
+    // `None` at the end of input
+    next: OnceCell<Option<Rc<Chunk>>>,
+}
+
+impl Chunk {
+    fn new(data: Vec<u8>) -> Self {
+        Self {
+            data,
+            next: OnceCell::new(),
+        }
+    }
+}
+
+impl Drop for Chunk {
+    // drop the rest of the chain iteratively, instead of recursing once per chunk
+    fn drop(&mut self) {
+        let mut next = self.next.take().flatten();
+        while let Some(chunk) = next {
+            match Rc::try_unwrap(chunk) {
+                Ok(mut chunk) => next = chunk.next.take().flatten(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+struct Source<R> {
+    reader: R,
+    // read into, then copied to a chunk of the length read
+    buffer: Vec<u8>,
+    error: Option<io::Error>,
+}
+
+impl<R: Read> Source<R> {
+    // `None` at the end of input, or on error
+    fn read_chunk(&mut self) -> Option<Rc<Chunk>> {
+        loop {
+            match self.reader.read(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(len) => return Some(Rc::new(Chunk::new(self.buffer[..len].to_vec()))),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => {
+                    self.error = Some(error);
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// Input iterator over the bytes of a [`std::io::Read`].
+///
+/// Clones are cheap cursors sharing one buffer.
+/// An I/O error ends the input; check it with [`ReadBytes::take_error`] after parsing.
+/// Errors hold cursors, which keep the buffer from their position alive; see the module documentation.
+pub struct ReadBytes<R> {
+    source: Rc<RefCell<Source<R>>>,
+    chunk: Rc<Chunk>,
+    index: usize,
+    offset: usize,
+}
+
+impl<R: Read> ReadBytes<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_chunk_size(reader, DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Read `chunk_size` bytes at a time from `reader`.
+    pub fn with_chunk_size(reader: R, chunk_size: usize) -> Self {
+        Self {
+            source: Rc::new(RefCell::new(Source {
+                reader,
+                buffer: vec![0; chunk_size.max(1)],
+                error: None,
+            })),
+            chunk: Rc::new(Chunk::new(Vec::new())),
+            index: 0,
+            offset: 0,
+        }
+    }
+
+    // move to the chunk holding the next byte; `false` at the end of input
+    fn fill(&mut self) -> bool {
+        while self.index == self.chunk.data.len() {
+            let next = self
+                .chunk
+                .next
+                .get_or_init(|| self.source.borrow_mut().read_chunk())
+                .clone();
+            match next {
+                Some(next) => {
+                    self.chunk = next;
+                    self.index = 0;
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        if self.fill() {
+            Some(self.chunk.data[self.index])
+        } else {
+            None
+        }
+    }
+}
+
+impl<R> ReadBytes<R> {
+    /// Number of bytes consumed from the start of the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Take the I/O error which ended the input, if any.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.source.borrow_mut().error.take()
+    }
+}
+
+impl<R> Clone for ReadBytes<R> {
+    fn clone(&self) -> Self {
+        Self {
+            source: Rc::clone(&self.source),
+            chunk: Rc::clone(&self.chunk),
+            index: self.index,
+            offset: self.offset,
+        }
+    }
+}
+
+impl<R> fmt::Debug for ReadBytes<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadBytes")
+            .field("offset", &self.offset)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> Iterator for ReadBytes<R> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let byte = self.peek()?;
+        self.index += 1;
+        self.offset += 1;
+        Some(byte)
+    }
+
+    // used for comparing positions of errors;
+    // the length of a stream is unknown, but the upper bound must decrease with each item
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.chunk.data.len() - self.index,
+            Some(usize::MAX - self.offset),
+        )
+    }
+}
+
+/// Input iterator over the UTF-8 `char`s of a [`std::io::Read`].
+///
+/// Invalid UTF-8 sequences are decoded as [`char::REPLACEMENT_CHARACTER`].
+/// See [`ReadBytes`].
+pub struct ReadChars<R> {
+    bytes: ReadBytes<R>,
+}
+
+impl<R: Read> ReadChars<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            bytes: ReadBytes::new(reader),
+        }
+    }
+
+    /// Read `chunk_size` bytes at a time from `reader`.
+    pub fn with_chunk_size(reader: R, chunk_size: usize) -> Self {
+        Self {
+            bytes: ReadBytes::with_chunk_size(reader, chunk_size),
+        }
+    }
+}
+
+impl<R> ReadChars<R> {
+    /// Number of bytes consumed from the start of the input.
+    pub fn offset(&self) -> usize {
+        self.bytes.offset()
+    }
+
+    /// Take the I/O error which ended the input, if any.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.bytes.take_error()
+    }
+}
+
+impl<R> Clone for ReadChars<R> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+
+impl<R> fmt::Debug for ReadChars<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadChars")
+            .field("offset", &self.bytes.offset)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> Iterator for ReadChars<R> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.bytes.next()?;
+        let len = match first {
+            0x00..=0x7F => return Some(first as char),
+            0xC2..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF4 => 4,
+            _ => return Some(char::REPLACEMENT_CHARACTER),
+        };
+        let mut buf = [first, 0, 0, 0];
+        for byte in buf.iter_mut().take(len).skip(1) {
+            // a byte which is not a continuation starts the next char
+            match self.bytes.peek() {
+                Some(next) if next & 0xC0 == 0x80 => {
+                    *byte = next;
+                    self.bytes.next();
+                }
+                _ => return Some(char::REPLACEMENT_CHARACTER),
+            }
+        }
+        Some(
+            std::str::from_utf8(&buf[..len])
+                .ok()
+                .and_then(|str| str.chars().next())
+                .unwrap_or(char::REPLACEMENT_CHARACTER),
+        )
+    }
+
+    // every char takes 1 to 4 bytes
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.bytes.size_hint();
+        (lower.div_ceil(4), upper)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::leaf::stringeq::StrEqualParser;
+    use crate::wrapper::repeat::RepeatParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::Parser;
+
+    #[test]
+    fn bytes() {
+        let input = ReadBytes::with_chunk_size("hello world".as_bytes(), 3);
+        let mut it = input.clone();
+        assert_eq!(it.by_ref().take(5).collect::<Vec<_>>(), b"hello");
+        assert_eq!(it.offset(), 5);
+
+        // clones are independent cursors
+        assert_eq!(input.collect::<Vec<_>>(), b"hello world");
+        assert_eq!(it.collect::<Vec<_>>(), b" world");
+    }
+
+    #[test]
+    fn chars() {
+        let input = ReadChars::with_chunk_size("héllo, 世界!".as_bytes(), 2);
+        assert_eq!(input.collect::<String>(), "héllo, 世界!");
+
+        let input = ReadChars::new(&[b'a', 0xE4, 0xB8, b'b', 0xFF][..]);
+        assert_eq!(input.collect::<String>(), "a\u{FFFD}b\u{FFFD}");
+    }
+
+    #[test]
+    fn short_chunk() {
+        let mut it = ReadBytes::with_chunk_size(&[0u8; 10][..], 8);
+        it.nth(8);
+        // the last chunk only holds the bytes read
+        assert_eq!(it.chunk.data.len(), 2);
+        assert_eq!(it.chunk.data.capacity(), 2);
+    }
+
+    #[test]
+    fn discard() {
+        let mut old = ReadBytes::with_chunk_size(&[0u8; 64][..], 4);
+        old.next();
+        let first_chunk = Rc::downgrade(&old.chunk);
+
+        let mut it = old.clone();
+        it.nth(9);
+        assert!(first_chunk.upgrade().is_some());
+
+        // no cursor points to the first chunk anymore
+        drop(old);
+        assert!(first_chunk.upgrade().is_none());
+        assert_eq!(it.count(), 53);
+    }
+
+    #[test]
+    fn piecewise() {
+        let line = SeqParser::new(
+            RepeatParser::from(SingleRangeParser::from('a'..='z'), 0..),
+            SingleRangeParser::from('\n'..='\n'),
+        );
+
+        let mut it = ReadChars::with_chunk_size("abcd\nefgh\nijkl\n".as_bytes(), 5);
+        // keep only the cursor; the error would keep the data from its position alive
+        it = line.parse(it).it;
+        // one chunk per line
+        let first_chunk = Rc::downgrade(&it.bytes.chunk);
+        assert_eq!(first_chunk.upgrade().unwrap().data, b"abcd\n");
+
+        // the data of the first line is released once its call returned
+        it = line.parse(it).it;
+        assert!(first_chunk.upgrade().is_none());
+        assert_eq!(it.offset(), 10);
+    }
+
+    #[test]
+    fn parse() {
+        let number = RepeatParser::from(SingleRangeParser::from('0'..='9'), 1..);
+        let parser = SeqParser::new(StrEqualParser::new("id="), number);
+
+        let res = parser.parse(ReadChars::with_chunk_size("id=1234;".as_bytes(), 3));
+        assert_eq!(res.output, Some((vec!['1', '2', '3', '4'],)));
+        assert_eq!(res.it.offset(), 7);
+        assert_eq!(res.it.collect::<String>(), ";");
+
+        let res = parser.parse(ReadChars::with_chunk_size("id=x".as_bytes(), 3));
+        assert_eq!(res.output, None);
+        assert_eq!(res.error.unwrap().it.offset(), 3);
+    }
+
+    #[test]
+    fn error() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disconnected"))
+            }
+        }
+
+        let mut input = ReadBytes::new(Failing);
+        assert_eq!(input.next(), None);
+        assert_eq!(input.take_error().unwrap().to_string(), "disconnected");
+        assert!(input.take_error().is_none());
+    }
+}
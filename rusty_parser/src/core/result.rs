@@ -1,4 +1,6 @@
+use super::error::ParseError;
 use super::iterator_bound::InputIteratorTrait;
+use super::partial::Incomplete;
 use super::tuple::Tuple;
 
 #[derive(Debug)]
@@ -16,4 +18,100 @@ where
     ///
     /// if parsing failed, this will be the same as the input iterator.
     pub it: It,
+
+    /// The furthest failure reached while parsing; where it failed and what was expected there.
+    ///
+    /// Since alternatives ( e.g. `or`, `optional`, `repeat` ) rewind the iterator on failure,
+    /// this may point further than [`ParseResult::it`],
+    /// and may be `Some` even if parsing succeeded.
+    /// `None` if no parser failed during parsing.
+    pub error: Option<ParseError<It>>,
+
+    /// Errors recovered during parsing, in order of occurrence.
+    ///
+    /// Filled by [`crate::IntoParser::recover_with`]; parsing may succeed even if this is not empty.
+    /// Always empty if parsing failed, since errors recovered in a backtracked attempt are discarded.
+    pub recovered: Vec<ParseError<It>>,
+
+    /// `true` if parsing passed a [`crate::cut`].
+    ///
+    /// A committed failure is a hard error; alternatives ( e.g. `or`, `optional`, `repeat` )
+    /// propagate it without trying other branches, and reset it once the alternative succeeds.
+    pub committed: bool,
+
+    /// `Some` if a parser ran out of input while parsing, whether parsing succeeded or not.
+    ///
+    /// If the input is only the data received so far, more data may change the result;
+    /// [`crate::parse_partial()`] reports it as [`Incomplete`].
+    pub incomplete: Option<Incomplete>,
+}
+
+impl<Output, It> ParseResult<Output, It>
+where
+    Output: Tuple,
+    It: InputIteratorTrait,
+{
+    /// Successful result with `output`, continuing from `it`.
+    pub fn success(output: Output, it: It) -> Self {
+        Self {
+            output: Some(output),
+            it,
+            error: None,
+            recovered: Vec::new(),
+            committed: false,
+            incomplete: None,
+        }
+    }
+
+    /// Failed result rewound to `it`, failed with `error`.
+    pub fn failure(it: It, error: ParseError<It>) -> Self {
+        Self {
+            output: None,
+            it,
+            error: Some(error),
+            recovered: Vec::new(),
+            committed: false,
+            incomplete: None,
+        }
+    }
+
+    /// Failed result rewound to `it`, without an error;
+    /// leaf parsers do not build errors under `match_pattern`.
+    pub(crate) fn unmatched(it: It) -> Self {
+        Self {
+            output: None,
+            it,
+            error: None,
+            recovered: Vec::new(),
+            committed: false,
+            incomplete: None,
+        }
+    }
+
+    /// Same result with its output mapped by `f`.
+    pub(crate) fn map_output<NewOutput: Tuple>(
+        self,
+        f: impl FnOnce(Output) -> NewOutput,
+    ) -> ParseResult<NewOutput, It> {
+        ParseResult {
+            output: self.output.map(f),
+            it: self.it,
+            error: self.error,
+            recovered: self.recovered,
+            committed: self.committed,
+            incomplete: self.incomplete,
+        }
+    }
+
+    /// Failed result rewound to `it`, keeping the error and the states of this result.
+    pub(crate) fn rewind<NewOutput: Tuple>(self, it: It) -> ParseResult<NewOutput, It> {
+        ParseResult {
+            output: None,
+            it,
+            error: self.error,
+            recovered: Vec::new(),
+            committed: self.committed,
+            incomplete: self.incomplete,
+        }
+    }
 }
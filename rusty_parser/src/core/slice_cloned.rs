@@ -0,0 +1,132 @@
+//! Input iterator over the items of a slice by value, which keeps the slice it iterates over.
+
+use std::fmt;
+
+use super::span::{InputOffset, RemainingLength, SliceInput};
+
+/// Input iterator over the items of a slice by value, like `slice.iter().cloned()`.
+///
+/// Unlike [`std::iter::Cloned`] and [`std::iter::Copied`], it keeps the slice it iterates over,
+/// so it implements [`SliceInput`] and [`InputOffset`] ( item indices ).
+pub struct SliceCloned<'a, T> {
+    slice: &'a [T],
+    pos: usize,
+}
+
+impl<'a, T> SliceCloned<'a, T> {
+    pub fn new(slice: &'a [T]) -> Self {
+        Self { slice, pos: 0 }
+    }
+
+    /// Remaining items, same as [`std::slice::Iter::as_slice`].
+    pub fn as_slice(&self) -> &'a [T] {
+        &self.slice[self.pos..]
+    }
+}
+
+// not derived; `T` need not be `Clone` to clone the cursor
+impl<T> Clone for SliceCloned<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            pos: self.pos,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SliceCloned<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SliceCloned")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T: Clone> Iterator for SliceCloned<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.slice.get(self.pos)?.clone();
+        self.pos += 1;
+        Some(item)
+    }
+
+    // used for comparing positions of errors
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len() - self.pos;
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for SliceCloned<'_, T> {}
+
+impl<'a, T> SliceInput for SliceCloned<'a, T> {
+    type Slice = &'a [T];
+
+    fn consumed(&self, rest: &Self) -> Self::Slice {
+        &self.slice[self.pos..rest.pos]
+    }
+}
+
+impl<T> InputOffset for SliceCloned<'_, T> {
+    fn offset(&self) -> usize {
+        self.pos
+    }
+}
+
+impl RemainingLength for SliceCloned<'_, u8> {
+    fn remaining_len(&self) -> usize {
+        self.slice.len() - self.pos
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::located::Located;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::recognize::RecognizeParser;
+    use crate::wrapper::repeat::RepeatParser;
+    use crate::wrapper::slice::SliceRefParser;
+    use crate::wrapper::spanned::SpannedParser;
+    use crate::Parser;
+
+    #[test]
+    fn slice_ref() {
+        let digits = RepeatParser::from(SingleRangeParser::from(b'0'..=b'9'), 1..);
+        let parser = SliceRefParser::new(digits);
+
+        let input = b"1234hello";
+        let res = parser.parse(SliceCloned::new(input));
+        let (digits,) = res.output.unwrap();
+        assert_eq!(digits, b"1234");
+        assert_eq!(digits.as_ptr(), input.as_ptr());
+        assert_eq!(res.it.as_slice(), b"hello");
+
+        // through `Located`
+        let res = parser.parse(Located::new(SliceCloned::new(input)));
+        assert_eq!(res.output, Some((&input[..4],)));
+        assert_eq!(res.it.location().column, 5);
+    }
+
+    #[test]
+    fn recognize_spanned() {
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        struct Token(i32);
+
+        let parser = SpannedParser::new(RecognizeParser::new(RepeatParser::from(
+            SingleRangeParser::from(Token(1)..=Token(3)),
+            1..,
+        )));
+
+        let input = [Token(0), Token(1), Token(2), Token(4)];
+        let mut it = SliceCloned::new(&input);
+        it.next();
+        let res = parser.parse(it);
+        let (tokens, raw, span) = res.output.unwrap();
+        assert_eq!(tokens, vec![Token(1), Token(2)]);
+        assert_eq!(raw, &input[1..3]);
+        assert_eq!(span.range(), Some(1..3));
+        assert_eq!(res.it.len(), 1);
+    }
+}
@@ -0,0 +1,283 @@
+//! Range of the input matched by a parser.
+
+use std::ops::Range;
+
+use super::located::{Located, LocatedItem};
+use super::read::{ReadBytes, ReadChars};
+
+/// Input iterator over a string or bytes, that can tell the length of the input left in bytes.
+///
+/// `Chars`, and iterators over `u8` slices.
+pub trait RemainingLength {
+    fn remaining_len(&self) -> usize;
+}
+
+impl RemainingLength for std::str::Chars<'_> {
+    fn remaining_len(&self) -> usize {
+        self.as_str().len()
+    }
+}
+impl RemainingLength for std::slice::Iter<'_, u8> {
+    fn remaining_len(&self) -> usize {
+        self.len()
+    }
+}
+impl RemainingLength for std::iter::Cloned<std::slice::Iter<'_, u8>> {
+    fn remaining_len(&self) -> usize {
+        self.len()
+    }
+}
+impl RemainingLength for std::iter::Copied<std::slice::Iter<'_, u8>> {
+    fn remaining_len(&self) -> usize {
+        self.len()
+    }
+}
+impl<It> RemainingLength for Located<It>
+where
+    It: RemainingLength + Iterator,
+    It::Item: LocatedItem,
+{
+    fn remaining_len(&self) -> usize {
+        self.inner().remaining_len()
+    }
+}
+
+/// Input iterator over a borrowed buffer, which can return the part of the buffer it consumed.
+///
+/// `&str` for `Chars`, `&[T]` for `slice::Iter` and [`crate::SliceCloned`].
+/// `std::iter::Copied` and `std::iter::Cloned` do not expose the slice they iterate over,
+/// so they cannot implement this trait; use [`crate::SliceCloned`] instead.
+pub trait SliceInput {
+    type Slice;
+
+    /// Part of the input between `self` and `rest`, where `rest` is `self` advanced by parsing.
+    fn consumed(&self, rest: &Self) -> Self::Slice;
+}
+
+impl<'a> SliceInput for std::str::Chars<'a> {
+    type Slice = &'a str;
+
+    fn consumed(&self, rest: &Self) -> Self::Slice {
+        let str = self.as_str();
+        &str[..str.len() - rest.as_str().len()]
+    }
+}
+impl<'a, T> SliceInput for std::slice::Iter<'a, T> {
+    type Slice = &'a [T];
+
+    fn consumed(&self, rest: &Self) -> Self::Slice {
+        let slice = self.as_slice();
+        &slice[..slice.len() - rest.len()]
+    }
+}
+impl<It> SliceInput for Located<It>
+where
+    It: SliceInput + Iterator,
+    It::Item: LocatedItem,
+{
+    type Slice = It::Slice;
+
+    fn consumed(&self, rest: &Self) -> Self::Slice {
+        self.inner().consumed(rest.inner())
+    }
+}
+
+/// Input iterator that knows its offset from the start of the input.
+///
+/// Bytes for [`Located`] and the reader inputs, items for [`crate::SliceCloned`].
+pub trait InputOffset {
+    /// Offset of the next item from the start of the input.
+    fn offset(&self) -> usize;
+}
+
+impl<It> InputOffset for Located<It> {
+    fn offset(&self) -> usize {
+        self.location().offset
+    }
+}
+impl<R> InputOffset for ReadBytes<R> {
+    fn offset(&self) -> usize {
+        ReadBytes::offset(self)
+    }
+}
+impl<R> InputOffset for ReadChars<R> {
+    fn offset(&self) -> usize {
+        ReadChars::offset(self)
+    }
+}
+
+/// Input iterator that can tell the span between two of its positions, for [`crate::IntoParser::spanned`].
+///
+/// Inputs implementing [`InputOffset`] give spans from the start of the input.
+/// `str::Chars` ( bytes ), `slice::Iter` and `Copied` / `Cloned` of it ( items )
+/// only know the length of the input left, so their spans are counted from the end of the input;
+/// resolve them with [`Span::range_in`].
+pub trait SpanInput {
+    /// Span of the input between `self` and `rest`, where `rest` is `self` advanced by parsing.
+    fn span_to(&self, rest: &Self) -> Span;
+}
+
+impl<It: InputOffset> SpanInput for It {
+    fn span_to(&self, rest: &Self) -> Span {
+        Span::new(self.offset(), rest.offset())
+    }
+}
+impl SpanInput for std::str::Chars<'_> {
+    fn span_to(&self, rest: &Self) -> Span {
+        Span::from_end(self.as_str().len(), rest.as_str().len())
+    }
+}
+impl<T> SpanInput for std::slice::Iter<'_, T> {
+    fn span_to(&self, rest: &Self) -> Span {
+        Span::from_end(self.len(), rest.len())
+    }
+}
+impl<'a, T: 'a + Clone> SpanInput for std::iter::Cloned<std::slice::Iter<'a, T>> {
+    fn span_to(&self, rest: &Self) -> Span {
+        Span::from_end(self.len(), rest.len())
+    }
+}
+impl<'a, T: 'a + Copy> SpanInput for std::iter::Copied<std::slice::Iter<'a, T>> {
+    fn span_to(&self, rest: &Self) -> Span {
+        Span::from_end(self.len(), rest.len())
+    }
+}
+
+/// Range of the input matched by a parser, from [`crate::IntoParser::spanned`].
+///
+/// Offsets are from the start of the input, as given by [`InputOffset`],
+/// or from the end of the input for inputs that only know the length left; see [`SpanInput`].
+/// [`Span::range_in`] resolves either against the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    start: usize,
+    end: usize,
+    // `start` and `end` are lengths of the input left, `start >= end`
+    from_end: bool,
+}
+
+impl Span {
+    pub(crate) fn new(start: usize, end: usize) -> Self {
+        debug_assert!(start <= end);
+        Self {
+            start,
+            end,
+            from_end: false,
+        }
+    }
+
+    // span between the positions with `start` and `end` items left
+    pub(crate) fn from_end(start: usize, end: usize) -> Self {
+        debug_assert!(start >= end);
+        Self {
+            start,
+            end,
+            from_end: true,
+        }
+    }
+
+    /// Offset of the start of the span.
+    ///
+    /// `None` if the input could not tell its offset; see [`Span::range_in`].
+    pub fn start(&self) -> Option<usize> {
+        (!self.from_end).then_some(self.start)
+    }
+
+    /// Offset of the end of the span, exclusive.
+    ///
+    /// `None` if the input could not tell its offset; see [`Span::range_in`].
+    pub fn end(&self) -> Option<usize> {
+        (!self.from_end).then_some(self.end)
+    }
+
+    /// Length of the span.
+    pub fn len(&self) -> usize {
+        self.end.abs_diff(self.start)
+    }
+
+    /// Whether the span is empty.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Offsets of the span, `start..end`.
+    ///
+    /// `None` if the input could not tell its offset; see [`Span::range_in`].
+    pub fn range(&self) -> Option<Range<usize>> {
+        (!self.from_end).then_some(self.start..self.end)
+    }
+
+    /// Offsets of the span in `source`, the whole input parsed, `start..end`.
+    ///
+    /// `source` is the `str` of a `Chars` input, or the slice of a slice iterator.
+    ///
+    /// # Panics
+    /// If `source` is shorter than the input left where the span starts.
+    pub fn range_in<T>(&self, source: &(impl AsRef<[T]> + ?Sized)) -> Range<usize> {
+        if self.from_end {
+            let len = source.as_ref().len();
+            len - self.start..len - self.end
+        } else {
+            self.start..self.end
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn range() {
+        let input = "héllo world";
+        let mut it = Located::new(input.chars());
+        it.nth(5);
+        let start = it.offset();
+        it.nth(1);
+        let span = Span::new(start, it.offset());
+
+        assert_eq!(span.len(), 2);
+        assert_eq!(span.range(), Some(7..9));
+        assert_eq!(span.range_in(input), 7..9);
+        assert_eq!(&input[span.range_in(input)], "wo");
+    }
+
+    #[test]
+    fn range_from_end() {
+        let input = "héllo world";
+        let mut it = input.chars();
+        it.nth(5);
+        let start = it.clone();
+        it.nth(1);
+        let span = start.span_to(&it);
+
+        assert_eq!(span.len(), 2);
+        assert_eq!(span.range(), None);
+        assert_eq!(span.start(), None);
+        assert_eq!(span.range_in(input), 7..9);
+        assert_eq!(&input[span.range_in(input)], "wo");
+
+        let input = [1, 2, 3, 4, 5];
+        let mut it = input.iter().copied();
+        it.next();
+        let start = it.clone();
+        it.nth(1);
+        assert_eq!(start.span_to(&it).range_in(&input), 1..3);
+    }
+
+    #[test]
+    fn consumed() {
+        let input = [1, 2, 3, 4, 5];
+        let i0 = input.iter();
+        let mut it = i0.clone();
+        it.nth(2);
+        let consumed = i0.consumed(&it);
+        assert_eq!(consumed, &[1, 2, 3]);
+        assert_eq!(consumed.as_ptr(), input.as_ptr());
+
+        let i0 = Located::new("héllo".chars());
+        let mut it = i0.clone();
+        it.nth(1);
+        assert_eq!(i0.consumed(&it), "hé");
+    }
+}
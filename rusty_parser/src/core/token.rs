@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+// tokens keep the value itself and format it only when displayed;
+// building one from a leaf parser copies a literal or an `Arc`, no allocation.
+
+/// An item or a sequence of items expected by a parser, e.g. `'a'`, `"hello"`.
+///
+/// Chars and strings are displayed quoted, integers as is.
+#[derive(Clone)]
+pub struct Token(TokenValue);
+
+#[derive(Clone)]
+enum TokenValue {
+    Literal(Literal),
+    String(Arc<str>),
+    Text(Cow<'static, str>),
+}
+
+/// Literal a leaf parser was built with, kept to describe it when it fails.
+///
+/// Only set where the type of the literal is known, e.g. `'a'.into_parser()` or `"let".into_parser()`;
+/// leaf parsers generic over their item ( e.g. `one`, `range` ) are not described.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Literal {
+    Char(char),
+    Signed(i128),
+    Unsigned(u128),
+    Str(&'static str),
+}
+
+impl From<char> for Literal {
+    fn from(value: char) -> Self {
+        Literal::Char(value)
+    }
+}
+
+macro_rules! literal_from_int {
+    ($variant:ident, $wide:ty, $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Literal {
+                fn from(value: $ty) -> Self {
+                    Literal::$variant(value as $wide)
+                }
+            }
+        )*
+    };
+}
+
+literal_from_int!(Signed, i128, i8, i16, i32, i64, i128, isize);
+literal_from_int!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+
+impl From<&'static str> for Literal {
+    fn from(value: &'static str) -> Self {
+        Literal::Str(value)
+    }
+}
+
+impl Token {
+    /// Description already formatted, displayed as is.
+    pub fn text(text: impl Into<Cow<'static, str>>) -> Self {
+        Token(TokenValue::Text(text.into()))
+    }
+
+    /// String shared with the parser that owns it, displayed quoted.
+    pub(crate) fn string(string: Arc<str>) -> Self {
+        Token(TokenValue::String(string))
+    }
+}
+
+impl TokenValue {
+    // strings compare equal whether borrowed by the parser or shared with it
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            TokenValue::Literal(Literal::Str(value)) => Some(value),
+            TokenValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (TokenValue::Literal(lhs), TokenValue::Literal(rhs)) if lhs == rhs => true,
+            (TokenValue::Text(lhs), TokenValue::Text(rhs)) => lhs == rhs,
+            (lhs, rhs) => lhs.as_str().is_some() && lhs.as_str() == rhs.as_str(),
+        }
+    }
+}
+impl Eq for Token {}
+
+impl From<Literal> for Token {
+    fn from(value: Literal) -> Self {
+        Token(TokenValue::Literal(value))
+    }
+}
+
+impl From<char> for Token {
+    fn from(value: char) -> Self {
+        Literal::Char(value).into()
+    }
+}
+
+impl From<&str> for Token {
+    fn from(value: &str) -> Self {
+        Token::string(value.into())
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            TokenValue::Literal(Literal::Char(value)) => write!(f, "{:?}", value),
+            TokenValue::Literal(Literal::Signed(value)) => write!(f, "{}", value),
+            TokenValue::Literal(Literal::Unsigned(value)) => write!(f, "{}", value),
+            TokenValue::Literal(Literal::Str(value)) => write!(f, "{:?}", value),
+            TokenValue::String(value) => write!(f, "{:?}", value),
+            TokenValue::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Token({})", self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Token::from('a').to_string(), "'a'");
+        assert_eq!(Token::from(Literal::from(1u8)).to_string(), "1");
+        assert_eq!(Token::from(Literal::from(-1i8)).to_string(), "-1");
+        assert_eq!(Token::from(Literal::from("let")).to_string(), "\"let\"");
+        assert_eq!(Token::from("let").to_string(), "\"let\"");
+        assert_eq!(Token::text("number").to_string(), "number");
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(Token::from(Literal::Char('a')), Token::from('a'));
+        assert_ne!(Token::from('a'), Token::from('b'));
+        assert_eq!(Token::from("GET"), Token::from(Literal::Str("GET")));
+        assert_ne!(Token::from("GET"), Token::text("GET"));
+    }
+}
@@ -0,0 +1,229 @@
+//! rustc-style report of a parse error, with the annotated source snippet.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::core::error::{ExpectedMessage, ParseError};
+use crate::core::located::Location;
+use crate::core::span::RemainingLength;
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Report of a [`ParseError`] on the source string.
+///
+/// Renders a header with the expected set, the file name with line and column,
+/// the offending line with a caret under the failed position, and the context frames as a note.
+#[derive(Debug, Clone)]
+pub struct Diagnostic<'a> {
+    source: &'a str,
+    offset: usize,
+    message: String,
+    context: Vec<Cow<'static, str>>,
+    file_name: Option<&'a str>,
+    notes: Vec<String>,
+    color: bool,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// Report of `error`, which was returned from parsing `source`.
+    ///
+    /// The error is located by the bytes left in its input;
+    /// if `source` is not the parsed input, the offset is clamped to a char boundary.
+    pub fn new<It: RemainingLength>(source: &'a str, error: &ParseError<It>) -> Self {
+        let mut offset = source.len().saturating_sub(error.it.remaining_len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        Self {
+            source,
+            offset,
+            message: ExpectedMessage(&error.expected).to_string(),
+            context: error.context.clone(),
+            file_name: None,
+            notes: Vec::new(),
+            color: false,
+        }
+    }
+
+    /// Set the file name printed with the location.
+    pub fn file_name(mut self, file_name: &'a str) -> Self {
+        self.file_name = Some(file_name);
+        self
+    }
+
+    /// Render with ANSI colors. Default is plain text.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Add a note printed at the end of the report.
+    pub fn note<Note: Into<String>>(mut self, note: Note) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Location of the error in the source.
+    pub fn location(&self) -> Location {
+        let before = &self.source[..self.offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Location {
+            offset: self.offset,
+            index: before.chars().count(),
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    fn paint(&self, color: &'static str) -> &'static str {
+        if self.color {
+            color
+        } else {
+            ""
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (red, blue, bold, reset) = (
+            self.paint(RED),
+            self.paint(BLUE),
+            self.paint(BOLD),
+            self.paint(RESET),
+        );
+        let location = self.location();
+
+        let line_start = self.source[..self.offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.source[self.offset..]
+            .find('\n')
+            .map_or(self.source.len(), |i| self.offset + i);
+        let line = self.source[line_start..line_end].trim_end_matches('\r');
+
+        let line_number = location.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        writeln!(f, "{red}error{reset}{bold}: {}{reset}", self.message)?;
+        write!(f, "{gutter}{blue}-->{reset} ")?;
+        if let Some(file_name) = self.file_name {
+            write!(f, "{}:", file_name)?;
+        }
+        writeln!(f, "{}:{}", location.line, location.column)?;
+        writeln!(f, "{gutter} {blue}|{reset}")?;
+        writeln!(f, "{blue}{line_number} |{reset} {line}")?;
+
+        // keep tabs so the caret is aligned with the line above
+        let padding: String = self.source[line_start..self.offset]
+            .chars()
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect();
+        let found = match self.source[self.offset..].chars().next() {
+            Some('\n') | Some('\r') => "unexpected end of line".to_string(),
+            Some(ch) => format!("unexpected {:?}", ch),
+            None => "unexpected end of input".to_string(),
+        };
+        write!(f, "{gutter} {blue}|{reset} {padding}{red}^ {found}{reset}")?;
+
+        if !self.context.is_empty() || !self.notes.is_empty() {
+            write!(f, "\n{gutter} {blue}|{reset}")?;
+        }
+        if !self.context.is_empty() {
+            write!(f, "\n{gutter} {blue}={reset} {bold}note{reset}: ")?;
+            for (i, context) in self.context.iter().rev().enumerate() {
+                if i > 0 {
+                    write!(f, " → ")?;
+                }
+                write!(f, "{}", context)?;
+            }
+        }
+        for note in self.notes.iter() {
+            write!(f, "\n{gutter} {blue}={reset} {bold}note{reset}: {}", note)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::error::Expected;
+
+    fn error_at(source: &str, offset: usize) -> ParseError<std::str::Chars<'_>> {
+        let mut error = ParseError::expected(source[offset..].chars(), Expected::Token(','.into()));
+        error.expected.push(Expected::Token(']'.into()));
+        error
+    }
+
+    #[test]
+    fn render() {
+        let source = "{\n  \"a\": [1, 2 x]\n}";
+        let mut error = error_at(source, 15);
+        error.context.push("in array".into());
+        error.context.push("in object".into());
+
+        let diagnostic = Diagnostic::new(source, &error).file_name("input.json");
+        assert_eq!(diagnostic.location().line, 2);
+        assert_eq!(diagnostic.location().column, 14);
+        assert_eq!(
+            diagnostic.to_string(),
+            "error: expected ',' or ']'\n \
+             --> input.json:2:14\n  \
+             |\n\
+             2 |   \"a\": [1, 2 x]\n  \
+             |              ^ unexpected 'x'\n  \
+             |\n  \
+             = note: in object → in array"
+        );
+    }
+
+    #[test]
+    fn end_of_input() {
+        let source = "[1, 2";
+        let error = error_at(source, 5);
+
+        let diagnostic = Diagnostic::new(source, &error).note("arrays must be closed");
+        assert_eq!(
+            diagnostic.to_string(),
+            "error: expected ',' or ']'\n \
+             --> 1:6\n  \
+             |\n\
+             1 | [1, 2\n  \
+             |      ^ unexpected end of input\n  \
+             |\n  \
+             = note: arrays must be closed"
+        );
+    }
+
+    #[test]
+    fn color() {
+        let source = "x";
+        let error = error_at(source, 0);
+        let rendered = Diagnostic::new(source, &error).color(true).to_string();
+        assert!(rendered.starts_with("\x1b[1;31merror\x1b[0m"));
+        assert!(rendered.contains("^ unexpected 'x'"));
+    }
+
+    #[test]
+    fn multi_byte() {
+        let source = "名前 = x";
+        let offset = source.find('x').unwrap();
+        let error = ParseError::expected(
+            source.as_bytes()[offset..].iter(),
+            Expected::Token('"'.into()),
+        );
+
+        let diagnostic = Diagnostic::new(source, &error);
+        assert_eq!(diagnostic.location().offset, offset);
+        assert_eq!(diagnostic.location().column, 6);
+        assert!(diagnostic.to_string().ends_with("|      ^ unexpected 'x'"));
+
+        // not the parsed input; the offset falls inside '名'
+        let error = ParseError::expected("12345678".chars(), Expected::Token('"'.into()));
+        let diagnostic = Diagnostic::new(source, &error);
+        assert_eq!(diagnostic.location().offset, 0);
+        assert!(diagnostic.to_string().ends_with("^ unexpected '名'"));
+    }
+}
@@ -1,8 +1,10 @@
 use std::iter::Iterator;
 
+use crate::core::error::{Expected, ParseError};
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -23,24 +25,18 @@ where
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let mut it = it;
         if let Some(val) = it.next() {
-            ParseResult {
-                output: Some((val,)),
-                it,
-            }
+            ParseResult::success((val,), it)
         } else {
-            ParseResult { output: None, it }
+            partial::exhausted(it.clone(), 1, Some(ParseError::expected(it, Expected::Any)))
         }
     }
 
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
         let mut it = it;
         if it.next().is_some() {
-            ParseResult {
-                output: Some(()),
-                it,
-            }
+            ParseResult::success((), it)
         } else {
-            ParseResult { output: None, it }
+            partial::exhausted(it, 1, None)
         }
     }
 }
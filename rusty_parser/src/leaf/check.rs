@@ -1,8 +1,10 @@
 use std::iter::Iterator;
 
+use crate::core::error::ParseError;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
 use crate::core::tuple::Tuple;
 
@@ -88,21 +90,12 @@ where
         let i0 = it.clone();
         if let Some(val) = it.next() {
             if let Some(res) = (self.closure)(val).to_option() {
-                ParseResult {
-                    output: Some(res),
-                    it,
-                }
+                ParseResult::success(res, it)
             } else {
-                ParseResult {
-                    output: None,
-                    it: i0,
-                }
+                ParseResult::failure(i0.clone(), ParseError::new(i0))
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            partial::exhausted(i0.clone(), 1, Some(ParseError::new(i0)))
         }
     }
 
@@ -111,21 +104,12 @@ where
         let i0 = it.clone();
         if let Some(val) = it.next() {
             if (self.closure)(val).to_option().is_some() {
-                ParseResult {
-                    output: Some(()),
-                    it,
-                }
+                ParseResult::success((), it)
             } else {
-                ParseResult {
-                    output: None,
-                    it: i0,
-                }
+                ParseResult::unmatched(i0)
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            partial::exhausted(i0, 1, None)
         }
     }
 }
@@ -158,7 +142,7 @@ mod tests {
     }
     #[test]
     fn success_test2() {
-        let a_parser = SingleCheckParser::new(|c: char| if c == 'a' { true } else { false });
+        let a_parser = SingleCheckParser::new(|c: char| c == 'a');
         let res = a_parser.parse("abcde".chars());
         assert_eq!(res.output, Some(()));
         let rest: String = res.it.collect();
@@ -174,7 +158,7 @@ mod tests {
     }
     #[test]
     fn fail_test2() {
-        let b_parser = SingleCheckParser::new(|c: char| if c == 'b' { true } else { false });
+        let b_parser = SingleCheckParser::new(|c: char| c == 'b');
         let res = b_parser.parse("abcde".chars());
         assert_eq!(res.output, None);
         let rest: String = res.it.collect();
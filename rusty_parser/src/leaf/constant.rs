@@ -29,17 +29,11 @@ where
     type Output = Output;
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        ParseResult {
-            output: Some(self.output.clone()),
-            it,
-        }
+        ParseResult::success(self.output.clone(), it)
     }
 
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
-        ParseResult {
-            output: Some(()),
-            it,
-        }
+        ParseResult::success((), it)
     }
 }
 
@@ -0,0 +1,187 @@
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+
+// parser that always success without consuming input,
+// and commits the alternative it belongs to;
+// the enclosing `or`, `optional`, `repeat`, ... resets the commitment once the alternative succeeds
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CutParser {}
+
+impl CutParser {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<It> Parser<It> for CutParser
+where
+    It: InputIteratorTrait,
+{
+    type Output = ();
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        ParseResult {
+            committed: true,
+            ..ParseResult::success((), it)
+        }
+    }
+}
+
+impl IntoParser for CutParser {
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wrapper::option::OptionalParser;
+    use crate::wrapper::or::OrParser;
+    use crate::wrapper::repeat::RepeatParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::wrapper::void::VoidParser;
+
+    #[test]
+    fn success() {
+        let res = CutParser::new().parse("a".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "a");
+        assert!(res.committed);
+    }
+
+    #[test]
+    fn or_not_backtracked() {
+        // 'a' cut 'b', or 'a' 'c'
+        let ab = VoidParser::new(SeqParser::new(
+            'a'.into_parser(),
+            SeqParser::new(CutParser::new(), 'b'.into_parser()),
+        ));
+        let ac = VoidParser::new(SeqParser::new('a'.into_parser(), 'c'.into_parser()));
+        let parser = OrParser::new(ab, ac);
+
+        let res = parser.parse("ab".chars());
+        assert_eq!(res.output, Some(()));
+        assert!(!res.committed);
+
+        // 'a' passed the cut, so 'a' 'c' is not tried
+        let res = parser.parse("ac".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "ac");
+        assert!(res.committed);
+        assert_eq!(res.error.unwrap().to_string(), "expected 'b'");
+
+        // failed before the cut; backtrack as usual
+        let res = parser.parse("xc".chars());
+        assert_eq!(res.output, None);
+        assert!(!res.committed);
+    }
+
+    #[test]
+    fn optional_repeat_propagate() {
+        let ab = VoidParser::new(SeqParser::new(
+            'a'.into_parser(),
+            SeqParser::new(CutParser::new(), 'b'.into_parser()),
+        ));
+
+        let parser = OptionalParser::new(ab);
+        let res = parser.parse("ax".chars());
+        assert_eq!(res.output, None);
+        assert!(res.committed);
+
+        let parser = RepeatParser::from(ab, 0..);
+        let res = parser.parse("ababax".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "ababax");
+        assert!(res.committed);
+
+        let res = parser.parse("ababx".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "x");
+        assert!(!res.committed);
+    }
+
+    #[test]
+    fn scoped_to_alternative() {
+        // s: 'a' cut 'b', or 'c'
+        let s = OrParser::new(
+            VoidParser::new(SeqParser::new(
+                'a'.into_parser(),
+                SeqParser::new(CutParser::new(), 'b'.into_parser()),
+            )),
+            VoidParser::new('c'.into_parser()),
+        );
+        // s 'x', or s 'y'
+        let parser = OrParser::new(
+            VoidParser::new(SeqParser::new(s, 'x'.into_parser())),
+            VoidParser::new(SeqParser::new(s, 'y'.into_parser())),
+        );
+
+        // `s` succeeded, so its cut does not commit the enclosing alternative
+        let res = parser.parse("aby".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+        assert!(!res.committed);
+
+        let res = parser.match_pattern("aby".chars());
+        assert_eq!(res.output, Some(()));
+
+        // failure inside `s` after the cut is still a hard error
+        let res = parser.parse("acy".chars());
+        assert_eq!(res.output, None);
+        assert!(res.committed);
+        assert_eq!(res.error.unwrap().to_string(), "expected 'b'");
+    }
+
+    #[test]
+    fn chained() {
+        use crate::IntoParser;
+
+        // 'a' cut 'b' built with method chaining, or 'a' 'c'
+        let parser = crate::or!('a'.seq(crate::cut()).seq('b').void(), 'a'.seq('c').void());
+
+        let res = parser.parse("ac".chars());
+        assert_eq!(res.output, None);
+        assert!(res.committed);
+        assert_eq!(res.error.unwrap().to_string(), "expected 'b'");
+
+        let res = parser.match_pattern("ac".chars());
+        assert_eq!(res.output, None);
+        assert!(res.committed);
+
+        let res = parser.parse("ab".chars());
+        assert_eq!(res.output, Some(()));
+        assert!(!res.committed);
+    }
+    #[test]
+    fn through_wrappers() {
+        use crate::IntoParser;
+
+        // the cut commits the rest of the enclosing sequence through `void` and `map`
+        let void = crate::or!(
+            'a'.seq(crate::cut()).void().seq('b').void(),
+            'a'.seq('c').void()
+        );
+        let res = void.parse("ac".chars());
+        assert_eq!(res.output, None);
+        assert!(res.committed);
+        assert_eq!(res.error.unwrap().to_string(), "expected 'b'");
+        let res = void.match_pattern("ac".chars());
+        assert_eq!(res.output, None);
+        assert!(res.committed);
+
+        let map = crate::or!(
+            'a'.seq(crate::cut()).map(|a| a).seq('b').void(),
+            'a'.seq('c').void()
+        );
+        let res = map.parse("ac".chars());
+        assert_eq!(res.output, None);
+        assert!(res.committed);
+        let res = map.parse("ab".chars());
+        assert_eq!(res.output, Some(()));
+        assert!(!res.committed);
+    }
+}
@@ -1,9 +1,11 @@
 use std::collections::BTreeMap;
 use std::iter::Iterator;
 
+use crate::core::error::ParseError;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial::Incomplete;
 use crate::core::result::ParseResult;
 use crate::core::tuple::Tuple;
 
@@ -55,22 +57,35 @@ where
     where
         CharIter: Iterator<Item = CharType> + Clone,
     {
+        let k0 = key.clone();
         if let Some(key_val) = key.next() {
             if let Some(child) = self.children.get(&key_val) {
-                if child.output.is_some() {
-                    return child.match_longest(
-                        key.clone(),
-                        ParseResult {
-                            output: child.output.clone(),
-                            it: key,
-                        },
-                    );
+                if let Some(output) = &child.output {
+                    return child
+                        .match_longest(key.clone(), ParseResult::success(output.clone(), key));
                 } else {
                     return child.match_longest(key, longest_matched);
                 }
             }
         }
-        longest_matched
+        // input ended inside of a longer key, which may match with more input
+        let incomplete = if k0.clone().next().is_none() && !self.children.is_empty() {
+            Some(Incomplete { needed: 1 })
+        } else {
+            None
+        };
+        if longest_matched.output.is_some() {
+            ParseResult {
+                incomplete,
+                ..longest_matched
+            }
+        } else {
+            // nothing matched; report the deepest node reached
+            ParseResult {
+                incomplete,
+                ..ParseResult::failure(longest_matched.it, ParseError::new(k0))
+            }
+        }
     }
 }
 
@@ -114,7 +129,7 @@ where
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         self.trie
-            .match_longest(it.clone(), ParseResult { output: None, it })
+            .match_longest(it.clone(), ParseResult::unmatched(it))
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::core::error::{Expected, ParseError};
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
@@ -22,11 +23,15 @@ where
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let mut i0 = it.clone();
         match i0.next() {
-            Some(_) => ParseResult { output: None, it },
-            None => ParseResult {
-                output: Some(()),
-                it,
-            },
+            Some(_) => ParseResult::failure(it.clone(), ParseError::expected(it, Expected::End)),
+            None => ParseResult::success((), it),
+        }
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let mut i0 = it.clone();
+        match i0.next() {
+            Some(_) => ParseResult::unmatched(it),
+            None => ParseResult::success((), it),
         }
     }
 }
@@ -54,4 +59,16 @@ mod tests {
         let res = end_parser.parse("a".chars());
         assert_eq!(res.output, None);
     }
+    #[test]
+    fn partial() {
+        let parser = crate::seq!("GET", ';', EndParser::new());
+
+        // matched up to the end; nothing ran out of input
+        let res = crate::parse_partial(&parser, "GET;".chars()).unwrap();
+        assert_eq!(res.output, Some((';',)));
+        assert_eq!(res.incomplete, None);
+
+        let res = crate::parse_partial(&parser, "GE".chars());
+        assert_eq!(res.unwrap_err(), crate::Incomplete { needed: 1 });
+    }
 }
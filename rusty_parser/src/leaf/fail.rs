@@ -1,3 +1,4 @@
+use crate::core::error::ParseError;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
@@ -19,11 +20,11 @@ where
     type Output = ();
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        ParseResult { output: None, it }
+        ParseResult::failure(it.clone(), ParseError::new(it))
     }
 
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
-        ParseResult { output: None, it }
+        ParseResult::failure(it.clone(), ParseError::new(it))
     }
 }
 
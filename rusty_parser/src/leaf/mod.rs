@@ -1,6 +1,7 @@
 pub mod any;
 pub mod check;
 pub mod constant;
+pub mod cut;
 pub mod dict_btree;
 pub mod dict_hashmap;
 pub mod end;
@@ -8,7 +8,8 @@ use crate::core::tuple::Tuple;
 /// This parser is for default value for `Box` parser.
 #[derive(Debug)]
 pub struct Panic<Output: Tuple> {
-    _phantom: std::marker::PhantomData<Output>,
+    // `fn() -> Output` keeps the parser `Send + Sync` for any `Output`
+    _phantom: std::marker::PhantomData<fn() -> Output>,
 }
 
 impl<Output: Tuple> Panic<Output> {
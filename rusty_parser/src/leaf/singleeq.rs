@@ -1,18 +1,46 @@
 use std::iter::Iterator;
 
+use crate::core::error::ParseError;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
+use crate::core::token::Literal;
 
 #[derive(Debug, Clone, Copy)]
 pub struct SingleEqualParser<CharacterType> {
     pub character: CharacterType,
+    literal: Option<Literal>,
 }
 
 impl<CharacterType> SingleEqualParser<CharacterType> {
     pub fn new(character: CharacterType) -> Self {
-        SingleEqualParser { character }
+        SingleEqualParser {
+            character,
+            literal: None,
+        }
+    }
+
+    /// Parser for a literal of known type, described by it on failure.
+    pub(crate) fn described(character: CharacterType) -> Self
+    where
+        CharacterType: Into<Literal> + Copy,
+    {
+        SingleEqualParser {
+            character,
+            literal: Some(character.into()),
+        }
+    }
+
+    fn error<It>(&self, it: It) -> ParseError<It>
+    where
+        It: InputIteratorTrait,
+    {
+        match self.literal {
+            Some(literal) => ParseError::token(it, literal.into()),
+            None => ParseError::new(it),
+        }
     }
 }
 
@@ -28,21 +56,12 @@ where
         let i0 = it.clone();
         if let Some(val) = it.next() {
             if val == self.character {
-                ParseResult {
-                    output: Some((val,)),
-                    it,
-                }
+                ParseResult::success((val,), it)
             } else {
-                ParseResult {
-                    output: None,
-                    it: i0,
-                }
+                ParseResult::failure(i0.clone(), self.error(i0))
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            partial::exhausted(i0.clone(), 1, Some(self.error(i0)))
         }
     }
 
@@ -51,21 +70,12 @@ where
         let i0 = it.clone();
         if let Some(val) = it.next() {
             if val == self.character {
-                ParseResult {
-                    output: Some(()),
-                    it,
-                }
+                ParseResult::success((), it)
             } else {
-                ParseResult {
-                    output: None,
-                    it: i0,
-                }
+                ParseResult::unmatched(i0)
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            partial::exhausted(i0, 1, None)
         }
     }
 }
@@ -80,79 +90,79 @@ impl<CharType> IntoParser for SingleEqualParser<CharType> {
 impl IntoParser for char {
     type Into = SingleEqualParser<char>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for i8 {
     type Into = SingleEqualParser<i8>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for i16 {
     type Into = SingleEqualParser<i16>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for i32 {
     type Into = SingleEqualParser<i32>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for i64 {
     type Into = SingleEqualParser<i64>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for i128 {
     type Into = SingleEqualParser<i128>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for isize {
     type Into = SingleEqualParser<isize>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for u8 {
     type Into = SingleEqualParser<u8>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for u16 {
     type Into = SingleEqualParser<u16>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for u32 {
     type Into = SingleEqualParser<u32>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for u64 {
     type Into = SingleEqualParser<u64>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for u128 {
     type Into = SingleEqualParser<u128>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 impl IntoParser for usize {
     type Into = SingleEqualParser<usize>;
     fn into_parser(self) -> Self::Into {
-        SingleEqualParser::new(self)
+        SingleEqualParser::described(self)
     }
 }
 
@@ -214,21 +224,12 @@ where
         let i0 = it.clone();
         if let Some(val) = it.next() {
             if (self.predicate)(val.clone(), &self.character) {
-                ParseResult {
-                    output: Some((val,)),
-                    it,
-                }
+                ParseResult::success((val,), it)
             } else {
-                ParseResult {
-                    output: None,
-                    it: i0,
-                }
+                ParseResult::failure(i0.clone(), ParseError::new(i0))
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            partial::exhausted(i0.clone(), 1, Some(ParseError::new(i0)))
         }
     }
 
@@ -237,21 +238,12 @@ where
         let i0 = it.clone();
         if let Some(val) = it.next() {
             if (self.predicate)(val, &self.character) {
-                ParseResult {
-                    output: Some(()),
-                    it,
-                }
+                ParseResult::success((), it)
             } else {
-                ParseResult {
-                    output: None,
-                    it: i0,
-                }
+                ParseResult::unmatched(i0)
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            partial::exhausted(i0, 1, None)
         }
     }
 }
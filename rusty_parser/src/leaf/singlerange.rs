@@ -1,6 +1,8 @@
+use crate::core::error::ParseError;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::range_copyable::{RangeBound, ToCopyable};
 use crate::core::result::ParseResult;
 
@@ -49,21 +51,12 @@ where
         let i0 = it.clone();
         if let Some(val) = it.next() {
             if self.range.contains(&val) {
-                ParseResult {
-                    output: Some((val,)),
-                    it,
-                }
+                ParseResult::success((val,), it)
             } else {
-                ParseResult {
-                    output: None,
-                    it: i0,
-                }
+                ParseResult::failure(i0.clone(), ParseError::new(i0))
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            partial::exhausted(i0.clone(), 1, Some(ParseError::new(i0)))
         }
     }
 
@@ -72,21 +65,12 @@ where
         let i0 = it.clone();
         if let Some(val) = it.next() {
             if self.range.contains(&val) {
-                ParseResult {
-                    output: Some(()),
-                    it,
-                }
+                ParseResult::success((), it)
             } else {
-                ParseResult {
-                    output: None,
-                    it: i0,
-                }
+                ParseResult::unmatched(i0)
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            partial::exhausted(i0, 1, None)
         }
     }
 }
@@ -149,6 +133,8 @@ where
 }
 
 #[cfg(test)]
+// exclusive ranges are intended to check the end is not included
+#[allow(clippy::almost_complete_range)]
 mod tests {
     use std::string::String;
 
@@ -1,9 +1,53 @@
 use std::iter::Iterator;
+use std::sync::Arc;
 
+use crate::core::error::ParseError;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
+use crate::core::token::{Literal, Token};
+
+// compare the input with `items` one by one;
+// `error` builds the error at the start, only called on failure; `None` under `match_pattern`
+fn parse_items<It, Items>(
+    it: It,
+    items: Items,
+    mut matches: impl FnMut(It::Item, Items::Item) -> bool,
+    error: impl FnOnce(It) -> Option<ParseError<It>>,
+) -> ParseResult<(), It>
+where
+    It: InputIteratorTrait,
+    Items: Iterator,
+{
+    let i0 = it.clone();
+    let mut it = it;
+    let mut items = items;
+    while let Some(item) = items.next() {
+        let matched = match it.next() {
+            Some(value) => matches(value, item),
+            None => {
+                return partial::exhausted(i0.clone(), 1 + items.count(), error(i0));
+            }
+        };
+        if !matched {
+            return ParseResult {
+                error: error(i0.clone()),
+                ..ParseResult::unmatched(i0)
+            };
+        }
+    }
+    ParseResult::success((), it)
+}
+
+// `'static` literals are described as they are, others by a copy made on failure
+fn str_token(string: &str, literal: Option<Literal>) -> Token {
+    match literal {
+        Some(literal) => literal.into(),
+        None => string.into(),
+    }
+}
 
 /// This Parser will compare the input string starts with the given &str.
 /// for borrowing-safety, the lifetime of str must be 'static.
@@ -11,10 +55,23 @@ use crate::core::result::ParseResult;
 #[derive(Debug, Clone, Copy)]
 pub struct StrEqualParser<'a> {
     string: &'a str,
+    literal: Option<Literal>,
 }
 impl<'a> StrEqualParser<'a> {
     pub fn new(string: &'a str) -> Self {
-        StrEqualParser { string }
+        StrEqualParser {
+            string,
+            literal: None,
+        }
+    }
+}
+impl StrEqualParser<'static> {
+    /// Parser for a `'static` literal, described without copying it.
+    pub(crate) fn described(string: &'static str) -> Self {
+        StrEqualParser {
+            string,
+            literal: Some(string.into()),
+        }
     }
 }
 impl<'a, It> Parser<It> for StrEqualParser<'a>
@@ -25,33 +82,15 @@ where
     type Output = ();
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        let i0 = it.clone();
-        let mut it = it;
-        // use take?
-        for ch in self.string.chars() {
-            match it.next() {
-                Some(ch2) => {
-                    if ch2 == ch {
-                        continue;
-                    } else {
-                        return ParseResult {
-                            output: None,
-                            it: i0,
-                        };
-                    }
-                }
-                None => {
-                    return ParseResult {
-                        output: None,
-                        it: i0,
-                    }
-                }
-            }
-        }
-        ParseResult {
-            output: Some(()),
+        parse_items(
             it,
-        }
+            self.string.chars(),
+            |a, b| a == b,
+            |i0| Some(ParseError::token(i0, str_token(self.string, self.literal))),
+        )
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        parse_items(it, self.string.chars(), |a, b| a == b, |_| None)
     }
 }
 impl<'a> IntoParser for StrEqualParser<'a> {
@@ -63,18 +102,20 @@ impl<'a> IntoParser for StrEqualParser<'a> {
 impl IntoParser for &'static str {
     type Into = StrEqualParser<'static>;
     fn into_parser(self) -> Self::Into {
-        StrEqualParser::new(self)
+        StrEqualParser::described(self)
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct StringEqualParser {
-    string: String,
+    string: Arc<str>,
 }
 
 impl StringEqualParser {
     pub fn new(string: String) -> Self {
-        StringEqualParser { string }
+        StringEqualParser {
+            string: string.into(),
+        }
     }
 }
 
@@ -86,7 +127,15 @@ where
     type Output = ();
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        StrEqualParser::new(&self.string).parse(it)
+        parse_items(
+            it,
+            self.string.chars(),
+            |a, b| a == b,
+            |i0| Some(ParseError::token(i0, Token::string(self.string.clone()))),
+        )
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        parse_items(it, self.string.chars(), |a, b| a == b, |_| None)
     }
 }
 
@@ -110,6 +159,7 @@ where
     Predicate: Fn(ItemType, char) -> bool,
 {
     string: &'a str,
+    literal: Option<Literal>,
     predicate: Predicate,
     _phantom: std::marker::PhantomData<ItemType>,
 }
@@ -120,6 +170,7 @@ where
     fn clone(&self) -> Self {
         Self {
             string: self.string,
+            literal: self.literal,
             predicate: self.predicate.clone(),
             _phantom: std::marker::PhantomData,
         }
@@ -136,6 +187,21 @@ where
     pub fn new(string: &'a str, predicate: Predicate) -> Self {
         Self {
             string,
+            literal: None,
+            predicate,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Predicate, ItemType> StrEqualByParser<'static, Predicate, ItemType>
+where
+    Predicate: Fn(ItemType, char) -> bool,
+{
+    /// Parser for a `'static` literal, described without copying it.
+    pub(crate) fn described(string: &'static str, predicate: Predicate) -> Self {
+        Self {
+            string,
+            literal: Some(string.into()),
             predicate,
             _phantom: std::marker::PhantomData,
         }
@@ -149,33 +215,12 @@ where
     type Output = ();
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        let i0 = it.clone();
-        let mut it = it;
-        // use take?
-        for ch in self.string.chars() {
-            match it.next() {
-                Some(ch2) => {
-                    if (self.predicate)(ch2, ch) {
-                        continue;
-                    } else {
-                        return ParseResult {
-                            output: None,
-                            it: i0,
-                        };
-                    }
-                }
-                None => {
-                    return ParseResult {
-                        output: None,
-                        it: i0,
-                    }
-                }
-            }
-        }
-        ParseResult {
-            output: Some(()),
-            it,
-        }
+        parse_items(it, self.string.chars(), &self.predicate, |i0| {
+            Some(ParseError::token(i0, str_token(self.string, self.literal)))
+        })
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        parse_items(it, self.string.chars(), &self.predicate, |_| None)
     }
 }
 impl<'a, Predicate, ItemType> IntoParser for StrEqualByParser<'a, Predicate, ItemType>
@@ -193,7 +238,7 @@ pub struct StringEqualByParser<Predicate, ItemType>
 where
     Predicate: Fn(ItemType, char) -> bool,
 {
-    string: String,
+    string: Arc<str>,
     predicate: Predicate,
     _phantom: std::marker::PhantomData<ItemType>,
 }
@@ -217,7 +262,7 @@ where
 {
     pub fn new(string: String, predicate: Predicate) -> Self {
         Self {
-            string,
+            string: string.into(),
             predicate,
             _phantom: std::marker::PhantomData,
         }
@@ -232,33 +277,12 @@ where
     type Output = ();
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        let i0 = it.clone();
-        let mut it = it;
-        // use take?
-        for ch in self.string.chars() {
-            match it.next() {
-                Some(ch2) => {
-                    if (self.predicate)(ch2, ch) {
-                        continue;
-                    } else {
-                        return ParseResult {
-                            output: None,
-                            it: i0,
-                        };
-                    }
-                }
-                None => {
-                    return ParseResult {
-                        output: None,
-                        it: i0,
-                    }
-                }
-            }
-        }
-        ParseResult {
-            output: Some(()),
-            it,
-        }
+        parse_items(it, self.string.chars(), &self.predicate, |i0| {
+            Some(ParseError::token(i0, Token::string(self.string.clone())))
+        })
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        parse_items(it, self.string.chars(), &self.predicate, |_| None)
     }
 }
 
@@ -293,33 +317,15 @@ where
     type Output = ();
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        let i0 = it.clone();
-        let mut it = it;
-        // use take?
-        for ch in self.slice.iter() {
-            match it.next() {
-                Some(ch2) => {
-                    if ch2 == *ch {
-                        continue;
-                    } else {
-                        return ParseResult {
-                            output: None,
-                            it: i0,
-                        };
-                    }
-                }
-                None => {
-                    return ParseResult {
-                        output: None,
-                        it: i0,
-                    }
-                }
-            }
-        }
-        ParseResult {
-            output: Some(()),
+        parse_items(
             it,
-        }
+            self.slice.iter(),
+            |a, b| a == *b,
+            |i0| Some(ParseError::new(i0)),
+        )
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        parse_items(it, self.slice.iter(), |a, b| a == *b, |_| None)
     }
 }
 
@@ -339,12 +345,12 @@ impl<T: 'static> IntoParser for &'static [T] {
 
 #[derive(Debug, Clone)]
 pub struct VecEqualParser<T> {
-    vec: Vec<T>,
+    vec: Arc<[T]>,
 }
 
 impl<T> VecEqualParser<T> {
     pub fn new(vec: Vec<T>) -> Self {
-        VecEqualParser { vec }
+        VecEqualParser { vec: vec.into() }
     }
 }
 
@@ -356,7 +362,15 @@ where
     type Output = ();
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        SliceEqualParser::new(&self.vec).parse(it)
+        parse_items(
+            it,
+            self.vec.iter(),
+            |a, b| a == *b,
+            |i0| Some(ParseError::new(i0)),
+        )
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        parse_items(it, self.vec.iter(), |a, b| a == *b, |_| None)
     }
 }
 
@@ -424,33 +438,12 @@ where
     type Output = ();
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        let i0 = it.clone();
-        let mut it = it;
-        // use take?
-        for ch in self.slice.iter() {
-            match it.next() {
-                Some(ch2) => {
-                    if (self.predicate)(ch2, ch) {
-                        continue;
-                    } else {
-                        return ParseResult {
-                            output: None,
-                            it: i0,
-                        };
-                    }
-                }
-                None => {
-                    return ParseResult {
-                        output: None,
-                        it: i0,
-                    }
-                }
-            }
-        }
-        ParseResult {
-            output: Some(()),
-            it,
-        }
+        parse_items(it, self.slice.iter(), &self.predicate, |i0| {
+            Some(ParseError::new(i0))
+        })
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        parse_items(it, self.slice.iter(), &self.predicate, |_| None)
     }
 }
 
@@ -469,7 +462,7 @@ pub struct VecEqualByParser<T, Predicate, ItemType>
 where
     Predicate: Fn(ItemType, &T) -> bool,
 {
-    vec: Vec<T>,
+    vec: Arc<[T]>,
     predicate: Predicate,
     _phantom: std::marker::PhantomData<ItemType>,
 }
@@ -477,7 +470,6 @@ where
 impl<T, Predicate, ItemType> Clone for VecEqualByParser<T, Predicate, ItemType>
 where
     Predicate: Fn(ItemType, &T) -> bool + Clone,
-    Vec<T>: Clone,
 {
     fn clone(&self) -> Self {
         Self {
@@ -494,7 +486,7 @@ where
 {
     pub fn new(vec: Vec<T>, predicate: Predicate) -> Self {
         VecEqualByParser {
-            vec,
+            vec: vec.into(),
             predicate,
             _phantom: std::marker::PhantomData,
         }
@@ -509,33 +501,12 @@ where
     type Output = ();
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        let i0 = it.clone();
-        let mut it = it;
-        // use take?
-        for ch in self.vec.iter() {
-            match it.next() {
-                Some(ch2) => {
-                    if (self.predicate)(ch2, ch) {
-                        continue;
-                    } else {
-                        return ParseResult {
-                            output: None,
-                            it: i0,
-                        };
-                    }
-                }
-                None => {
-                    return ParseResult {
-                        output: None,
-                        it: i0,
-                    }
-                }
-            }
-        }
-        ParseResult {
-            output: Some(()),
-            it,
-        }
+        parse_items(it, self.vec.iter(), &self.predicate, |i0| {
+            Some(ParseError::new(i0))
+        })
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        parse_items(it, self.vec.iter(), &self.predicate, |_| None)
     }
 }
 
@@ -582,4 +553,44 @@ mod test {
         let rest: String = res.it.collect();
         assert_eq!(&rest, "hell_world!!");
     }
+
+    #[test]
+    fn borrowed() {
+        // literals borrowed for less than 'static, described by a copy
+        let string = String::from("hello");
+        let res = StrEqualParser::new(string.as_str()).parse("help".chars());
+        assert_eq!(res.error.unwrap().to_string(), "expected \"hello\"");
+
+        // items of generic type are not described
+        let bytes = b"GET".to_vec();
+        let res = SliceEqualParser::new(bytes.as_slice()).parse(b"PUT".iter().copied());
+        assert_eq!(res.error.unwrap().to_string(), "unexpected input");
+    }
+
+    #[test]
+    fn match_pattern_without_error() {
+        let res = "hello".into_parser().match_pattern("help".chars());
+        assert_eq!(res.output, None);
+        assert!(res.error.is_none());
+
+        let res = "hello".into_parser().parse("help".chars());
+        assert_eq!(res.error.unwrap().to_string(), "expected \"hello\"");
+    }
+
+    #[test]
+    fn incomplete() {
+        use crate::core::partial::Incomplete;
+
+        let parser = StrEqualParser::new("hello");
+        let res = parser.parse("hel".chars());
+        assert_eq!(res.output, None);
+        assert!(!res.committed);
+        assert_eq!(res.it.as_str(), "hel");
+        assert_eq!(res.incomplete, Some(Incomplete { needed: 2 }));
+        assert_eq!(res.error.unwrap().it.as_str(), "hel");
+
+        // genuine mismatch
+        let res = parser.parse("hex".chars());
+        assert_eq!(res.incomplete, None);
+    }
 }
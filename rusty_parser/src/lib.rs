@@ -52,6 +52,12 @@
 //! when you only want to check if the pattern is matched or not, without extracting data.
 //! For some parsers, like [`IntoParser::repeat`], it is expensive to call [`crate::parse()`] to get the output since it invokes [`Vec::push`] inside.
 //!
+//! [`ParseResult::error`] holds a [`crate::ParseError`] of the furthest failure reached during parsing,
+//! which tells the position of the failure and the set of items expected there.
+//!
+//! [`crate::parse_partial()`] parses input that arrives in chunks;
+//! it returns [`crate::Incomplete`] if a parser ran out of input before it could decide.
+//!
 //! ### Note
 //!  - Since the [`crate::parse()`] internally clones the iterator, the iterator must be cheaply clonable.
 //!  - `Output` must be `Tuple`, including `()`. If you want to return a single value, use `(Value,)`.
@@ -69,20 +75,30 @@
 //! | [`crate::check`] | Check one charactor with closure | `(T,)` |
 //! | [`crate::any`] | Match any charactor | `(Iterator::Item,)` |
 //! | [`crate::DictBTree`], [`crate::DictHashMap`] | Trie Dictionary | `T` |
-//! | [`crate::DynBoxChars`], [`crate::DynBoxSlice`], [`crate::DynBoxSliceCopied`] | Dynamic Parser that can take any parser with same `Output` | `T` |
+//! | [`crate::DynBox`], [`crate::DynBoxFor`], [`crate::DynBoxChars`], [`crate::DynBoxSlice`], [`crate::DynBoxSliceCopied`] | Dynamic Parser that can take any parser with same `Output` | `T` |
+//! | [`crate::DynBoxSync`], [`crate::DynBoxForSync`], [`crate::DynBoxCharsSync`], [`crate::DynBoxSliceSync`], [`crate::DynBoxSliceCopiedSync`] | `Send + Sync` version of `DynBox*` | `T` |
 //!
 //! ### Combinators
 //! | Combinator | Description | Output |
 //! | :------: | ----------- | :------: |
 //! | [`seq!`] | Sequence of parsers | `( *<Output of A>, *<Output of B> ... )`(Tuple Concatenated ) |
 //! | [`or!`] | Or combinator | `Output` of the all parsers |
+//! | [`crate::Choice`] | Or combinator over parsers added at runtime | `Output` of the all parsers |
+//! | [`permutation!`] | Match each parser exactly once, in any order | `( *<Output of A>, *<Output of B> ... )`(Tuple Concatenated ) |
 //! | [`IntoParser::map`] | Map the output of the parser | `(T,)` |
+//! | [`IntoParser::try_map`] | Map the output of the parser with a fallible closure | `(T,)` |
+//! | [`IntoParser::verify`] | Reject the match if the predicate on the output fails | `Output` of `self` |
+//! | [`IntoParser::and_then`] | Build the parser for the rest of the input from the output | `Output` of the returned parser |
 //! | [`IntoParser::repeat`] | Repeat the parser multiple times | `(Vec<Output of Self>,)` |
+//! | [`IntoParser::repeat_until`] | Repeat the parser until the terminator matches | `(Vec<Output of Self>, *<Output of end>)` |
+//! | [`IntoParser::sep_by`], [`IntoParser::sep_by1`] | Repeat the parser multiple times, separated by another parser | `(Vec<Output of Self>,)` |
 //! | [`IntoParser::optional`] | Success whether the pattern is matched or not | `( Option<Output of Self>, )` |
 //! | [`IntoParser::optional_or`] | Success whether the pattern is matched or not | `Output` of `Self` |
 //! | [`IntoParser::not`] | Match for Pattern1 to success and Pattern2 to fail | `Output` of `Self` |
 //! | [`IntoParser::reduce_left`], [`IntoParser::reduce_right`] | Reduce the output of the parser | `Output` of `Self` |
 //! | [`IntoParser::reduce_with`], [`IntoParser::reduce_right_with`] | Reduce the output of the parser with initial value | `Init` |
+//! | [`crate::pratt`] | Expression of atoms and prefix, infix, postfix operators by precedence | `Output` of atom |
+//! | [`crate::recursive`], [`crate::recursive2`], [`crate::recursive3`] | Rules referring to themselves, or to each other | `Output` of the rule |
 //!
 //!
 //! ### Others
@@ -92,11 +108,27 @@
 //! | [`crate::end`] | Success if it reached to the end of input | `()` |
 //! | [`crate::fail`] | Always fail | `()` |
 //! | [`IntoParser::void`] | Ignore the output of the parser | `()` |
+//! | [`IntoParser::between`], [`IntoParser::preceded_by`], [`IntoParser::terminated_by`], [`IntoParser::padded`] | Match surrounding parsers, ignoring their output | `Output` of `Self` |
 //! | [`IntoParser::output`] | Change Parser's Output to `(output,)` | `(T,)` |
 //! | [`IntoParser::string`], [`IntoParser::vec`] | Captures the matched range into `String` or `Vec<T>` | `(String,)` or `(Vec<Iterator::Item>,)` |
+//! | [`IntoParser::slice_str`] | Borrows the matched range from the input string, without allocation | `(&str,)` |
+//! | [`IntoParser::slice_ref`] | Borrows the matched range from the input buffer, without allocation | `(&[T],)` or `(&str,)` |
 //! | [`IntoParser::not_consume`] | Check if the pattern is matched or not, without consuming the input | `Output` of `Self` |
+//! | [`IntoParser::spanned`] | Append the span of the matched input to the output | `( *<Output of Self>, Span )` |
+//! | [`IntoParser::recognize`] | Append the matched range, borrowed from the input, to the output | `( *<Output of Self>, &[T] )` |
+//! | [`crate::cut`] | Commit the sequence; failure after this becomes a hard error | `()` |
+//!
+//! ### Error Reporting
+//! | Parser | Description | Output |
+//! | :------: | ----------- | :------: |
+//! | [`IntoParser::label`] | Name the parser, to report `expected <label>` on failure | `Output` of `Self` |
+//! | [`IntoParser::context`] | Add a context frame to the error reported from inside of the parser | `Output` of `Self` |
+//! | [`IntoParser::recover_with`] | On failure, record the error and parse the recovery parser instead | `Output` of `Self` |
+//! | [`crate::skip_until`] | Skip items until the pattern matches | `()` |
+//! | [`crate::recover_between`] | Skip nested pairs of open and close patterns | `()` |
 
 pub(crate) mod core;
+pub(crate) mod diagnostic;
 pub(crate) mod leaf;
 pub(crate) mod wrapper;
 
@@ -118,10 +150,213 @@ pub use core::parser::Parser;
 /// struct that holds the result of parsing.
 pub use core::result::ParseResult;
 
+/// Error describing where the parser failed and what was expected there.
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let parser = rp::seq!('a', rp::or!('b', 'c'));
+///
+/// let res = rp::parse(&parser, "ad".chars());
+/// assert_eq!(res.output, None);
+///
+/// let error = res.error.unwrap();
+/// assert_eq!(error.to_string(), "expected 'b' or 'c'");
+/// assert_eq!(error.found(), Some('d'));
+/// assert_eq!(error.it.as_str(), "d");
+/// ```
+pub use core::error::ParseError;
+
+/// Item of [`ParseError::expected`] set.
+pub use core::error::Expected;
+
+/// [`ParseError::expected`] set; dereferences to a slice of [`Expected`].
+pub use core::error::ExpectedSet;
+
+/// Item or sequence expected by a leaf parser, in [`Expected::Token`].
+///
+/// Only chars, integers and strings converted with [`IntoParser`] or [`str`] describe themselves;
+/// other leaf parsers ( e.g. [`one`], [`range`], slices ) report an unexpected input,
+/// name them with [`IntoParser::label`].
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let res = rp::parse(&'a'.into_parser(), "x".chars());
+/// let error = res.error.unwrap();
+/// assert_eq!(error.expected, vec![rp::Expected::Token('a'.into())]);
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// struct Custom(i32);
+/// let res = rp::parse(&rp::one(Custom(1)), vec![Custom(2)].into_iter());
+/// assert_eq!(res.error.unwrap().to_string(), "unexpected input");
+/// let res = rp::parse(&rp::one(Custom(1)).label("custom"), vec![Custom(2)].into_iter());
+/// assert_eq!(res.error.unwrap().to_string(), "expected custom");
+/// ```
+pub use core::token::Token;
+
+/// Input iterator adapter that tracks the [`Location`] ( byte offset, line and column ) of the items consumed.
+///
+/// Works with every parser as any other input iterator.
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let parser = rp::seq!("let", ' ', ('a'..='z').repeat(1..), ';');
+///
+/// let res = rp::parse(&parser, rp::Located::new("let x\n".chars()));
+/// assert_eq!(res.output, None);
+///
+/// let location = res.error.unwrap().it.location();
+/// assert_eq!(location.line, 1);
+/// assert_eq!(location.column, 6);
+/// assert_eq!(location.offset, 5);
+/// ```
+pub use core::located::Located;
+
+/// Location in the input, from [`Located::location`].
+pub use core::located::Location;
+
+/// Item of the input which [`Located`] can track; `char` and `u8`.
+pub use core::located::LocatedItem;
+
+/// Input iterator over the items of a slice by value, which keeps the slice it iterates over.
+///
+/// Use it instead of `slice.iter().cloned()` or `slice.iter().copied()`
+/// for [`IntoParser::slice_ref`], [`IntoParser::recognize`] and [`IntoParser::spanned`].
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let digits = (b'0'..=b'9').repeat(1..).slice_ref().spanned();
+///
+/// let input = b"1234hello";
+/// let res = rp::parse(&digits, rp::SliceCloned::new(input));
+/// let (digits, span) = res.output.unwrap();
+/// assert_eq!(digits, b"1234");
+/// assert_eq!(span.range(), Some(0..4));
+/// assert_eq!(res.it.as_slice(), b"hello");
+/// ```
+pub use core::slice_cloned::SliceCloned;
+
+/// Input iterator over the bytes of a [`std::io::Read`], with a shared buffer.
+///
+/// Clones are cheap cursors into the buffer,
+/// and data before the oldest live cursor is discarded.
+/// A `parse` call keeps the data from where it started until it returns,
+/// so parse a long stream one top-level item per call, continuing from [`ParseResult::it`].
+///
+/// A [`ParseError`] holds a cursor as well, so the furthest error in [`ParseResult::error`]
+/// keeps the data from its position alive even after parsing succeeded.
+/// When parsing a long stream piece by piece, keep only the error's `offset()` and drop it.
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let reader = std::io::Cursor::new(b"\x01\x02\x03payload".to_vec());
+/// let parser = rp::seq!(1u8, 2u8, 3u8).void();
+///
+/// let res = rp::parse(&parser, rp::ReadBytes::new(reader));
+/// assert_eq!(res.output, Some(()));
+/// assert_eq!(res.it.offset(), 3);
+/// assert!(res.it.take_error().is_none());
+/// ```
+pub use core::read::ReadBytes;
+
+/// Input iterator over the UTF-8 `char`s of a [`std::io::Read`], with a shared buffer.
+///
+/// See [`ReadBytes`].
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let reader = std::io::Cursor::new("id=1234;".as_bytes());
+/// let parser = rp::seq!("id=", ('0'..='9').repeat(1..));
+///
+/// let res = rp::parse(&parser, rp::Located::new(rp::ReadChars::new(reader)));
+/// assert_eq!(res.output.unwrap(), (vec!['1', '2', '3', '4'],));
+/// assert_eq!(res.it.location().column, 8);
+/// ```
+pub use core::read::ReadChars;
+
+/// Range of the input matched by a parser, from [`IntoParser::spanned`].
+pub use core::span::Span;
+
+/// Input iterator that knows its offset from the start of the input.
+pub use core::span::InputOffset;
+
+/// Input iterator that can tell the span between two of its positions, for [`IntoParser::spanned`].
+pub use core::span::SpanInput;
+
+/// Input iterator that can tell the length of the input left, for [`Diagnostic`].
+pub use core::span::RemainingLength;
+
+/// Input iterator over a borrowed buffer, for [`IntoParser::slice_ref`] and [`IntoParser::recognize`].
+pub use core::span::SliceInput;
+
+/// Parsing stopped at the end of the partial input, from [`crate::parse_partial()`].
+pub use core::partial::Incomplete;
+
+/// Whether a separator may follow the last element of [`IntoParser::sep_by`].
+pub use wrapper::sep_by::Trailing;
+
+/// rustc-style report of a [`ParseError`], with the annotated source snippet.
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let parser = rp::seq!('[', ('0'..='9').label("digit"), ']').context("in array");
+///
+/// let source = "[x]";
+/// let res = rp::parse(&parser, source.chars());
+/// let error = res.error.unwrap();
+///
+/// // `color(true)` for ANSI colors
+/// let diagnostic = rp::Diagnostic::new(source, &error).file_name("input.txt");
+/// assert_eq!(
+///     diagnostic.to_string(),
+///     "error: expected digit
+///  --> input.txt:1:2
+///   |
+/// 1 | [x]
+///   |  ^ unexpected 'x'
+///   |
+///   = note: in array"
+/// );
+/// ```
+pub use diagnostic::Diagnostic;
+
 /// A trait alias that Input Iterator must hold.
 pub use crate::core::iterator_bound::InputIteratorTrait;
 
 /// Parse the input with the given parser.
+///
+/// [`ParseResult::error`] holds the furthest failure reached during parsing,
+/// even if it was backtracked by an alternative.
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// // "ab" or "a"
+/// let parser = rp::or!(rp::seq!('a', 'b').void(), 'a'.void());
+///
+/// let res = rp::parse(&parser, "ac".chars());
+/// assert_eq!(res.output, Some(()));
+/// assert_eq!(res.it.as_str(), "c");
+///
+/// // 'a' matched, but "ab" failed at 'c'
+/// let error = res.error.unwrap();
+/// assert_eq!(error.to_string(), "expected 'b'");
+/// assert_eq!(error.it.as_str(), "c");
+/// ```
 pub fn parse<ParserType, It>(
     parser: &ParserType,
     it: It,
@@ -146,6 +381,68 @@ where
     parser.match_pattern(it)
 }
 
+/// Parse the partial input with the given parser, for input that arrives in chunks.
+///
+/// The end of the input is only the end of the data received so far.
+/// Returns [`Incomplete`] if any parser tried ran out of input, see [`ParseResult::incomplete`];
+/// append more data and parse again from the start.
+/// Otherwise, same as [`crate::parse()`].
+///
+/// [`crate::end`] matches at the end of the data received so far, without reporting [`Incomplete`];
+/// the grammar is complete once everything before it is.
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// // "GET" or "GETS", then digits and ';'
+/// let parser = rp::seq!(
+///     rp::or!("GETS", "GET"),
+///     ('0'..='9').repeat(1..).string(),
+///     ';'
+/// );
+///
+/// // "GE" may continue to "GET" or "GETS"; the smallest `needed` is reported
+/// let res = rp::parse_partial(&parser, "GE".chars());
+/// assert_eq!(res.unwrap_err(), rp::Incomplete { needed: 1 });
+///
+/// // more digits may follow
+/// let res = rp::parse_partial(&parser, "GET12".chars());
+/// assert_eq!(res.unwrap_err(), rp::Incomplete { needed: 1 });
+///
+/// let res = rp::parse_partial(&parser, "GET12;GET".chars()).unwrap();
+/// assert_eq!(res.output.unwrap(), ("12".to_string(), ';'));
+/// assert_eq!(res.it.as_str(), "GET");
+///
+/// // genuine mismatch
+/// let res = rp::parse_partial(&parser, "PUT".chars()).unwrap();
+/// assert_eq!(res.output, None);
+/// ```
+pub fn parse_partial<ParserType, It>(
+    parser: &ParserType,
+    it: It,
+) -> Result<ParseResult<<ParserType as Parser<It>>::Output, It>, Incomplete>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    core::partial::finish(parser.parse(it))
+}
+
+/// Match pattern of the partial input with the given parser, for input that arrives in chunks.
+///
+/// See [`crate::parse_partial()`] and [`crate::match_pattern()`].
+pub fn match_pattern_partial<ParserType, It>(
+    parser: &ParserType,
+    it: It,
+) -> Result<ParseResult<(), It>, Incomplete>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    core::partial::finish(parser.match_pattern(it))
+}
+
 /// Check one character is equal to the given character.
 ///
 /// `Output`: `(Iterator::Item,)`
@@ -225,7 +522,7 @@ where
 /// let hello_parser = "hello".into_parser();
 /// ```
 pub fn str(str: &'static str) -> leaf::stringeq::StrEqualParser<'static> {
-    leaf::stringeq::StrEqualParser::new(str)
+    leaf::stringeq::StrEqualParser::described(str)
 }
 
 /// Compare the input string starts with the given string. With given equality function.
@@ -251,7 +548,7 @@ pub fn str_by<Predicate, ItemType>(
 where
     Predicate: Fn(ItemType, char) -> bool,
 {
-    leaf::stringeq::StrEqualByParser::new(str, predicate)
+    leaf::stringeq::StrEqualByParser::described(str, predicate)
 }
 
 /// Compare the input string starts with the given string.
@@ -419,6 +716,37 @@ pub fn end() -> leaf::end::EndParser {
     leaf::end::EndParser::new()
 }
 
+/// This Parser will always succeed without consuming input,
+/// and commits the sequence it belongs to.
+///
+/// Once a sequence passed `cut`, failure of the rest of the sequence becomes a hard error;
+/// `or`, `optional`, `repeat` and others propagate it without trying other branches.
+/// The cut is scoped to the alternative containing it: once that alternative succeeds, enclosing alternatives backtrack as usual.
+/// Wrappers do not end the commitment; in `'a'.seq(rp::cut()).void().seq('b')` the cut commits `'b'` too.
+/// [`IntoParser::recover_with`] is the only combinator that catches it.
+///
+/// `Output`: `()`
+///
+/// # Example
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// // once '"' is matched, it must be a string
+/// let letter = ('a'..='z').label("letter");
+/// let string = rp::seq!('"'.void(), rp::cut(), letter.repeat(0..), '"'.void());
+/// let value = rp::or!(string.void(), rp::str("\"\"\"").void());
+///
+/// let res = rp::parse(&value, "\"abc".chars());
+/// assert_eq!(res.output, None);
+/// assert!(res.committed);
+/// // the error of the string, not of the other alternatives
+/// assert_eq!(res.error.unwrap().to_string(), "expected letter or '\"'");
+/// ```
+pub fn cut() -> leaf::cut::CutParser {
+    leaf::cut::CutParser::new()
+}
+
 /// This Parser will always fail.
 ///
 /// `Output`: `()`
@@ -482,6 +810,144 @@ pub fn any() -> leaf::any::AnyParser {
     leaf::any::AnyParser::new()
 }
 
+/// Skip items until `until` matches, consuming `until` as well.
+///
+/// Fails if `until` was not found until the end of input.
+/// Consumes at least one item; `until` matching nothing at the start does not count.
+/// Useful for [`IntoParser::recover_with`] to resynchronize the input after an error.
+///
+/// `Output`: `()`
+///
+/// # Example
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let parser = rp::skip_until(';');
+/// let res = rp::parse(&parser, "abc;def".chars());
+/// assert_eq!(res.output.unwrap(), ());
+/// assert_eq!(res.it.collect::<String>(), "def");
+/// ```
+pub fn skip_until<UntilParser: IntoParser>(
+    until: UntilParser,
+) -> wrapper::recover::SkipUntilParser<UntilParser::Into> {
+    wrapper::recover::SkipUntilParser::new(until.into_parser())
+}
+
+/// Match `open`, then skip items until the matching `close`, respecting nested pairs.
+///
+/// Fails if the input does not start with `open`, or the pairs are not closed until the end of input.
+/// Useful for [`IntoParser::recover_with`] to skip a broken block.
+///
+/// `Output`: `()`
+///
+/// # Example
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let parser = rp::recover_between('{', '}');
+/// let res = rp::parse(&parser, "{a{b}c}d".chars());
+/// assert_eq!(res.output.unwrap(), ());
+/// assert_eq!(res.it.collect::<String>(), "d");
+/// ```
+pub fn recover_between<OpenParser: IntoParser, CloseParser: IntoParser>(
+    open: OpenParser,
+    close: CloseParser,
+) -> wrapper::recover::RecoverBetweenParser<OpenParser::Into, CloseParser::Into> {
+    wrapper::recover::RecoverBetweenParser::new(open.into_parser(), close.into_parser())
+}
+
+/// Expression of `atom`s and operators, parsed by precedence ( Pratt parsing ).
+///
+/// Add operators to the returned parser with `.prefix(op, precedence, fold)`, `.infix(op, assoc, precedence, fold)`,
+/// `.postfix(op, precedence, fold)`, and groups like parentheses with `.group(open, close)`.
+/// Higher `precedence` binds tighter; output of the operators is discarded,
+/// and `fold` closures take the output of the operands.
+/// Operators are tried in the order they are added.
+///
+/// `Output`: `(T,)`, where `(T,)` is `Output` of `atom`
+///
+/// # Example
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let num = rp::range('0'..='9').map(|ch: char| ch.to_digit(10).unwrap() as i32);
+/// let expr = rp::pratt(num)
+///     .group('(', ')')
+///     .infix('+', rp::Assoc::Left, 1, |a: i32, b| a + b)
+///     .infix('*', rp::Assoc::Left, 2, |a: i32, b| a * b)
+///     .prefix('-', 3, |a: i32| -a);
+///
+/// let res = rp::parse(&expr, "-(1+2)*3+4".chars());
+/// assert_eq!(res.output.unwrap(), (-5,));
+/// ```
+pub fn pratt<Atom: IntoParser>(
+    atom: Atom,
+) -> wrapper::pratt::PrattParser<Atom::Into, wrapper::pratt::NoOperators> {
+    wrapper::pratt::PrattParser::new(atom.into_parser())
+}
+
+/// Associativity of an infix operator of [`pratt`].
+pub use wrapper::pratt::Assoc;
+
+/// Define a recursive rule, referring to itself.
+///
+/// The closure takes a reference to the rule and returns its definition.
+/// Since the type of the definition would contain itself, wrap it with [`DynBoxChars`] or the others.
+/// The rule is defined as soon as the closure returns, so it cannot be left unassigned.
+/// The reference is only meant to be a part of the rules; parsing with it inside the closure,
+/// or after all the returned parsers are dropped, fails without consuming the input.
+///
+/// The references do not borrow a `RefCell` on every parse, and do not leak the rules through an `Rc` cycle.
+/// For mutually recursive rules, use [`recursive2`] or [`recursive3`].
+///
+/// `Output`: `Output` of the rule
+///
+/// # Example
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// // list: '[' (list (',' list)*)? ']'
+/// let list = rp::recursive(|list| {
+///     rp::DynBoxChars::new(list.sep_by(',', 0..).between('[', ']').map(|items: Vec<usize>| items.len()))
+/// });
+///
+/// let res = rp::parse(&list, "[[],[[]],[]]".chars());
+/// assert_eq!(res.output.unwrap(), (3,));
+/// ```
+pub use wrapper::recursive::recursive;
+
+/// Define two mutually recursive rules. See [`recursive`].
+///
+/// # Example
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// // expr: term ('+' term)*
+/// // term: digit | '(' expr ')'
+/// let (expr, _term) = rp::recursive2(|expr, term| {
+///     let digit = rp::range('0'..='9').map(|ch: char| ch.to_digit(10).unwrap());
+///     (
+///         rp::DynBoxChars::new(term.sep_by('+', 1..).map(|terms: Vec<u32>| terms.iter().sum::<u32>())),
+///         rp::DynBoxChars::new(rp::or!(digit, expr.between('(', ')'))),
+///     )
+/// });
+///
+/// let res = rp::parse(&expr, "1+(2+3)".chars());
+/// assert_eq!(res.output.unwrap(), (6,));
+/// ```
+pub use wrapper::recursive::recursive2;
+
+/// Define three mutually recursive rules. See [`recursive`].
+pub use wrapper::recursive::recursive3;
+
+/// Reference to a rule being defined by [`recursive`], to annotate the closure's parameter.
+pub use wrapper::recursive::RecursiveRef;
+
 /// Dictionary using trie, implementation uses [`std::collections::BTreeMap`]; O(log(N)) search.
 ///
 /// This will match as long as possible, regardless of the order of insertion.
@@ -562,6 +1028,56 @@ pub use leaf::dict_hashmap::DictHashMapParser as DictHashMap;
 /// ```
 pub use wrapper::boxed::chars::DynBoxChars;
 
+/// A [`Box<dyn Parser>`] wrapper for any input iterator `It`.
+///
+/// This can take any parser with Output of `Output`.
+///
+/// Once you wrap the parser with this, you can only use input iterator of `It`.
+/// For iterators borrowing the input, e.g. [`std::str::CharIndices`], use [`DynBoxFor`]
+/// to parse input of any lifetime.
+///
+/// [`Default`] is implemented, with always-panic-parser
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// // tokens from a lexer
+/// let tokens = vec![1, 2, 3];
+///
+/// let mut parser = rp::DynBox::<std::vec::IntoIter<i32>, (i32,)>::default();
+/// parser.assign(0..=9);
+/// let res = rp::parse(&parser, tokens.into_iter());
+/// assert_eq!(res.output.unwrap(), (1,));
+/// ```
+pub use wrapper::boxed::generic::DynBox;
+
+/// A [`Box<dyn Parser>`] wrapper for input iterators borrowing the input, of any lifetime.
+///
+/// The input iterator type is given as [`InputFamily`].
+/// See [`DynBox`].
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// struct CharIndices;
+/// impl rp::InputFamily for CharIndices {
+///     type Input<'a> = std::str::CharIndices<'a>;
+/// }
+///
+/// let mut parser = rp::DynBoxFor::<CharIndices, (usize,)>::default();
+/// parser.assign(rp::check(|(i, ch): (usize, char)| ch.is_ascii_digit().then_some(i)));
+///
+/// let input = String::from("123");
+/// let res = rp::parse(&parser, input.char_indices());
+/// assert_eq!(res.output.unwrap(), (0,));
+/// ```
+pub use wrapper::boxed::generic::DynBoxFor;
+
+/// Family of input iterators borrowing the input for any lifetime, for [`DynBoxFor`].
+pub use wrapper::boxed::generic::InputFamily;
+
 /// A [`Box<dyn Parser>`] wrapper for iterators of [`std::iter::Cloned<std::slice::Iter>`].
 ///
 /// This can take any parser with Output of `Output`.
@@ -606,6 +1122,78 @@ pub use wrapper::boxed::slice_cloned::DynBoxSlice;
 /// ```
 pub use wrapper::boxed::slice_copied::DynBoxSliceCopied;
 
+/// `Send + Sync` version of [`DynBoxChars`], for grammars shared across threads.
+///
+/// This can only take parsers which are `Send + Sync`.
+/// Share it with [`std::sync::Arc`], and make it recursive with [`std::sync::OnceLock`],
+/// which implements [`Parser`] like [`std::rc::Rc`] and [`std::cell::RefCell`].
+/// `std::sync::LazyLock` implements [`Parser`] too with the `lazy-lock` feature, which requires Rust 1.80.
+/// Do not make it recursive through [`std::sync::RwLock`] or [`std::sync::Mutex`];
+/// locking again from the same thread may deadlock or panic.
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+/// use std::sync::{Arc, OnceLock};
+///
+/// // paren: '(' paren? ')'
+/// static PAREN: OnceLock<Arc<OnceLock<rp::DynBoxCharsSync<()>>>> = OnceLock::new();
+///
+/// fn paren() -> &'static Arc<OnceLock<rp::DynBoxCharsSync<()>>> {
+///     PAREN.get_or_init(|| {
+///         let paren = Arc::new(OnceLock::new());
+///         let body = rp::seq!('(', Arc::clone(&paren).optional(), ')').void();
+///         let _ = paren.set(rp::DynBoxCharsSync::new(body));
+///         paren
+///     })
+/// }
+///
+/// let worker = std::thread::spawn(|| rp::parse(paren(), "(())".chars()).output);
+/// assert_eq!(worker.join().unwrap(), Some(()));
+/// ```
+pub use wrapper::boxed::sync::DynBoxCharsSync;
+
+/// `Send + Sync` version of [`DynBox`]. See [`DynBoxCharsSync`].
+pub use wrapper::boxed::sync::DynBoxSync;
+
+/// `Send + Sync` version of [`DynBoxFor`]. See [`DynBoxCharsSync`].
+pub use wrapper::boxed::sync::DynBoxForSync;
+
+/// `Send + Sync` version of [`DynBoxSlice`]. See [`DynBoxCharsSync`].
+pub use wrapper::boxed::sync::DynBoxSliceSync;
+
+/// `Send + Sync` version of [`DynBoxSliceCopied`]. See [`DynBoxCharsSync`].
+pub use wrapper::boxed::sync::DynBoxSliceCopiedSync;
+
+/// Or combinator over a [`Vec`] of parsers of the same type, assembled at runtime.
+///
+/// The parsers are tried in order, with the same result as [`or!`];
+/// after a failure past [`cut`], the rest are not tried. With no parsers, it always fails.
+/// Use [`DynBoxChars`] and the others to hold different parsers with the same `Output`.
+///
+/// `Output`: `Output` of the parsers
+///
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// // keywords from a configuration
+/// let mut keywords: rp::Choice<rp::DynBoxChars<(usize,)>> = ["let", "fn"]
+///     .into_iter()
+///     .enumerate()
+///     .map(|(id, keyword)| rp::DynBoxChars::new(rp::string(keyword.to_string()).output(id)))
+///     .collect();
+/// keywords.push(rp::DynBoxChars::new(rp::str("if").output(2)));
+///
+/// let res = rp::parse(&keywords, "fn main".chars());
+/// assert_eq!(res.output.unwrap(), (1,));
+///
+/// keywords.remove(1);
+/// let res = rp::parse(&keywords, "fn main".chars());
+/// assert_eq!(res.output, None);
+/// ```
+pub use wrapper::choice::Choice;
+
 // ================== useful macros below ==================
 
 /// A binary or combinator
@@ -614,6 +1202,9 @@ pub use wrapper::or::or;
 /// A binary seq combinator
 pub use wrapper::seq::seq;
 
+/// A permutation combinator over parsers nested as `(a, (b, (c, ())))`. See [`permutation!`].
+pub use wrapper::permutation::permutation;
+
 /// A macro for creating a sequence of parsers.
 ///
 /// # Example
@@ -659,3 +1250,53 @@ macro_rules! or {
         $crate::or( $first, $crate::or!($($rest),+) )
     };
 }
+
+/// A macro for matching each parser exactly once, in any order.
+///
+/// Each round, the first unmatched parser which consumes input is taken.
+/// Parsers left unmatched must match without consuming input, e.g. with [`IntoParser::optional`];
+/// so an optional member may appear anywhere, not only where it is declared.
+///
+/// Whether a parser consumed input is told by the upper bound of [`Iterator::size_hint`] of the input.
+/// For inputs without one, every match counts as consuming, so an optional parser matches where it is declared,
+/// and the failures of the parsers are reported together, as if they failed at the same position.
+///
+/// The outputs are concatenated in the declaration order, the same as [`seq!`].
+///
+/// # Example
+/// ```rust
+/// use rusty_parser as rp;
+/// use rp::IntoParser;
+///
+/// let digit = rp::range('0'..='9');
+/// let size = rp::seq!('s'.void(), digit);
+/// let color = rp::seq!('c'.void(), ('a'..='z').into_parser());
+/// let bold = 'b'.void().optional();
+/// let style = rp::permutation!(size, color, bold);
+///
+/// let res = rp::parse(&style, "bcrs4".chars());
+/// assert_eq!(res.output.unwrap(), ('4', 'r', Some(())));
+///
+/// let res = rp::parse(&style, "s4cr".chars());
+/// assert_eq!(res.output.unwrap(), ('4', 'r', None));
+///
+/// // each parser at most once
+/// let res = rp::parse(&style, "s4s5cr".chars());
+/// assert!(res.output.is_none());
+/// ```
+#[macro_export]
+macro_rules! permutation {
+    (@members) => {
+        ()
+    };
+    (@members $first:expr $(, $rest:expr)*) => {
+        (
+            $crate::IntoParser::into_parser($first),
+            $crate::permutation!(@members $($rest),*)
+        )
+    };
+
+    ($($parser:expr),+ $(,)?) => {
+        $crate::permutation($crate::permutation!(@members $($parser),+))
+    };
+}
@@ -0,0 +1,172 @@
+use crate::core::error;
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::partial;
+use crate::core::result::ParseResult;
+use crate::wrapper::tupleutils::unpack::TupleUnpack;
+
+// Callback takes Parser's output as input;
+// Callback function's return value is the parser for the rest of the input,
+// and its output would be new value of the parser
+
+#[derive(Debug, Clone, Copy)]
+pub struct AndThenParser<ParserType, ClosureType> {
+    parser: ParserType,
+    callback: ClosureType,
+}
+
+impl<ParserType, ClosureType> AndThenParser<ParserType, ClosureType> {
+    pub fn new(parser: ParserType, callback: ClosureType) -> Self {
+        Self { parser, callback }
+    }
+}
+
+impl<ParserType, ClosureType, It> Parser<It> for AndThenParser<ParserType, ClosureType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+    ClosureType: TupleUnpack<<ParserType as Parser<It>>::Output>,
+    ClosureType::Output: IntoParser,
+    <ClosureType::Output as IntoParser>::Into: Parser<It>,
+{
+    type Output = <<ClosureType::Output as IntoParser>::Into as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let res_a = self.parser.parse(it);
+        let Some(val_a) = res_a.output else {
+            return res_a.rewind(i0);
+        };
+        let parser_b = self.callback.map(val_a).into_parser();
+        let res_b = parser_b.parse(res_a.it);
+        if res_b.output.is_some() {
+            ParseResult {
+                output: res_b.output,
+                it: res_b.it,
+                error: error::merge(res_a.error, res_b.error),
+                recovered: error::concat(res_a.recovered, res_b.recovered),
+                committed: res_a.committed || res_b.committed,
+                incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
+            }
+        } else {
+            ParseResult {
+                output: None,
+                it: i0,
+                error: error::merge(res_a.error, res_b.error),
+                recovered: Vec::new(),
+                // failed after cut
+                committed: res_a.committed || res_b.committed,
+                incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
+            }
+        }
+    }
+
+    // the first parser must be parsed to build the second one
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let i0 = it.clone();
+        let res_a = self.parser.parse(it);
+        let Some(val_a) = res_a.output else {
+            return res_a.rewind(i0);
+        };
+        let parser_b = self.callback.map(val_a).into_parser();
+        let res_b = parser_b.match_pattern(res_a.it);
+        if res_b.output.is_some() {
+            ParseResult {
+                output: Some(()),
+                it: res_b.it,
+                error: error::merge(res_a.error, res_b.error),
+                recovered: error::concat(res_a.recovered, res_b.recovered),
+                committed: res_a.committed || res_b.committed,
+                incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
+            }
+        } else {
+            ParseResult {
+                output: None,
+                it: i0,
+                error: error::merge(res_a.error, res_b.error),
+                recovered: Vec::new(),
+                // failed after cut
+                committed: res_a.committed || res_b.committed,
+                incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
+            }
+        }
+    }
+}
+
+impl<ParserType, ClosureType> IntoParser for AndThenParser<ParserType, ClosureType> {
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::any::AnyParser;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::map::MapParser;
+    use crate::wrapper::or::OrParser;
+    use crate::wrapper::repeat::RepeatParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::wrapper::void::VoidParser;
+
+    fn length_prefixed() -> impl for<'a> Parser<std::str::Chars<'a>, Output = (Vec<char>,)> {
+        let count = MapParser::new(SingleRangeParser::from('0'..='9'), |ch: char| {
+            ch.to_digit(10).unwrap() as usize
+        });
+        AndThenParser::new(count, |count: usize| {
+            RepeatParser::from(AnyParser::new(), count)
+        })
+    }
+
+    #[test]
+    fn success1() {
+        let parser = length_prefixed();
+
+        let res = parser.parse("3abcde".chars());
+        assert_eq!(res.output, Some((vec!['a', 'b', 'c'],)));
+        assert_eq!(res.it.as_str(), "de");
+
+        let res = parser.match_pattern("3abcde".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "de");
+
+        // not enough items
+        let res = parser.parse("3ab".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "3ab");
+        assert_eq!(res.error.unwrap().it.as_str(), "");
+    }
+
+    #[test]
+    fn compose() {
+        // rewinds for the next alternative
+        let parser = OrParser::new(
+            SeqParser::new(
+                length_prefixed(),
+                VoidParser::new(SingleEqualParser::new(';')),
+            ),
+            RepeatParser::from(AnyParser::new(), 0..),
+        );
+        let res = parser.parse("2ab;".chars());
+        assert_eq!(res.output, Some((vec!['a', 'b'],)));
+        let res = parser.parse("2abc;".chars());
+        assert_eq!(res.output, Some((vec!['2', 'a', 'b', 'c', ';'],)));
+
+        // the second parser depends on the first output
+        let tagged = AndThenParser::new(AnyParser::new(), |tag: char| {
+            OrParser::new(
+                MapParser::new(SingleEqualParser::new(tag), |_| true),
+                MapParser::new(AnyParser::new(), |_| false),
+            )
+        });
+        let res = tagged.parse("aab".chars());
+        assert_eq!(res.output, Some((true,)));
+        assert_eq!(res.it.as_str(), "b");
+        let res = tagged.parse("abb".chars());
+        assert_eq!(res.output, Some((false,)));
+    }
+}
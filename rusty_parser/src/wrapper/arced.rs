@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+
+impl<ParserType, It> Parser<It> for Arc<ParserType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.as_ref().parse(it)
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.as_ref().match_pattern(it)
+    }
+}
+
+impl<ParserType> IntoParser for Arc<ParserType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::RwLock;
+
+    use super::*;
+    use crate::{
+        leaf::{singleeq::SingleEqualParser, singlerange::SingleRangeParser},
+        wrapper::boxed::sync::DynBoxCharsSync,
+    };
+
+    #[test]
+    fn threads() {
+        let digit_parser = SingleRangeParser::from('0'..='9');
+        let digit_boxed: DynBoxCharsSync<(char,)> = DynBoxCharsSync::new(digit_parser);
+
+        // let 2 parsers point to the same parser
+        let arc1 = Arc::new(RwLock::new(digit_boxed));
+        let arc2 = Arc::clone(&arc1);
+
+        let res = std::thread::spawn(move || arc2.parse("123".chars()).output)
+            .join()
+            .unwrap();
+        assert_eq!(res, Some(('1',)));
+
+        // change the shared parser from this thread
+        arc1.write().unwrap().assign(SingleEqualParser::new('a'));
+        let arc2 = Arc::clone(&arc1);
+        let res = std::thread::spawn(move || arc2.parse("123".chars()).output)
+            .join()
+            .unwrap();
+        assert_eq!(res, None);
+    }
+}
@@ -88,13 +88,17 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::leaf::singleeq::SingleEqualParser;
     use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::option::OptionalParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::wrapper::void::VoidParser;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     #[test]
     fn success1() {
         let digit_parser = SingleRangeParser::from('0'..='9');
-        let a_parser = SingleEqualParser::new('a');
+        let a_parser = 'a'.into_parser();
 
         let str = "1a2b3c4d5e6f7g8h9i0j";
         let mut boxed: DynBoxChars<(char,)> = DynBoxChars::new(digit_parser);
@@ -118,4 +122,27 @@ mod test {
         boxed.parse("123".chars());
         boxed.match_pattern("123".chars());
     }
+
+    #[test]
+    fn error_recursive() {
+        // paren: '(' paren? ')'
+        let paren = Rc::new(RefCell::new(DynBoxChars::<()>::default()));
+        let paren_body = SeqParser::new(
+            SeqParser::new(
+                VoidParser::new('('.into_parser()),
+                OptionalParser::new(Rc::clone(&paren)),
+            ),
+            VoidParser::new(')'.into_parser()),
+        );
+        paren.borrow_mut().assign(VoidParser::new(paren_body));
+
+        let res = paren.parse("((x))".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "((x))");
+
+        // points to the innermost failure, not the start of the outermost parser
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "x))");
+        assert_eq!(error.to_string(), "expected '(' or ')'");
+    }
 }
@@ -0,0 +1,284 @@
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+use crate::core::tuple::Tuple;
+
+use crate::leaf::panic::Panic;
+
+pub struct DynBox<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    parser: std::boxed::Box<dyn Parser<It, Output = Output>>,
+}
+
+impl<It, Output> DynBox<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    pub fn new<ParserType: IntoParser>(parser: ParserType) -> Self
+    where
+        ParserType::Into: Parser<It, Output = Output> + 'static,
+    {
+        Self {
+            parser: std::boxed::Box::new(parser.into_parser()),
+        }
+    }
+    pub fn assign<ParserType: IntoParser>(&mut self, parser: ParserType)
+    where
+        ParserType::Into: Parser<It, Output = Output> + 'static,
+    {
+        self.parser = std::boxed::Box::new(parser.into_parser());
+    }
+}
+
+/// default to dummy parser that always panic
+impl<It, Output> Default for DynBox<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple + 'static,
+{
+    fn default() -> Self {
+        Self::new(Panic::new())
+    }
+}
+
+impl<It, Output> Parser<It> for DynBox<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    type Output = Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.parser.parse(it)
+    }
+
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<It, Output> Deref for DynBox<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    type Target = std::boxed::Box<dyn Parser<It, Output = Output>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parser
+    }
+}
+impl<It, Output> DerefMut for DynBox<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parser
+    }
+}
+impl<It, Output> IntoParser for DynBox<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+/// Family of input iterators borrowing the input for any lifetime, e.g. `std::str::CharIndices<'a>`.
+pub trait InputFamily {
+    type Input<'a>: InputIteratorTrait;
+}
+
+type DynFamilyParser<Family, Output> =
+    dyn for<'a> Parser<<Family as InputFamily>::Input<'a>, Output = Output>;
+
+pub struct DynBoxFor<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    parser: std::boxed::Box<DynFamilyParser<Family, Output>>,
+}
+
+impl<Family, Output> DynBoxFor<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    pub fn new<ParserType: IntoParser>(parser: ParserType) -> Self
+    where
+        ParserType::Into: for<'a> Parser<Family::Input<'a>, Output = Output> + 'static,
+    {
+        Self {
+            parser: std::boxed::Box::new(parser.into_parser()),
+        }
+    }
+    pub fn assign<ParserType: IntoParser>(&mut self, parser: ParserType)
+    where
+        ParserType::Into: for<'a> Parser<Family::Input<'a>, Output = Output> + 'static,
+    {
+        self.parser = std::boxed::Box::new(parser.into_parser());
+    }
+}
+
+/// default to dummy parser that always panic
+impl<Family, Output> Default for DynBoxFor<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple + 'static,
+{
+    fn default() -> Self {
+        Self::new(Panic::new())
+    }
+}
+
+impl<'a, Family, Output> Parser<Family::Input<'a>> for DynBoxFor<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    type Output = Output;
+
+    fn parse(&self, it: Family::Input<'a>) -> ParseResult<Self::Output, Family::Input<'a>> {
+        self.parser.parse(it)
+    }
+
+    fn match_pattern(&self, it: Family::Input<'a>) -> ParseResult<(), Family::Input<'a>> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<Family, Output> Deref for DynBoxFor<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    type Target = std::boxed::Box<DynFamilyParser<Family, Output>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parser
+    }
+}
+impl<Family, Output> DerefMut for DynBoxFor<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parser
+    }
+}
+impl<Family, Output> IntoParser for DynBoxFor<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::check::SingleCheckParser;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::wrapper::option::OptionalParser;
+    use crate::wrapper::output::OutputParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::wrapper::void::VoidParser;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // token iterator of a lexer
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Token {
+        Open,
+        Close,
+        Num(i32),
+    }
+    type Tokens = std::vec::IntoIter<Token>;
+
+    #[test]
+    fn success1() {
+        let num_parser = SingleCheckParser::new(|token: Token| match token {
+            Token::Num(num) => Some(num),
+            _ => None,
+        });
+
+        let tokens = vec![Token::Num(1), Token::Open];
+        let mut boxed: DynBox<Tokens, (i32,)> = DynBox::new(num_parser);
+        let res = boxed.parse(tokens.into_iter());
+        assert_eq!(res.output, Some((1,)));
+
+        // set another parser to same variable
+        boxed.assign(OutputParser::new(SingleEqualParser::new(Token::Open), 0));
+        let res = boxed.parse(res.it);
+        assert_eq!(res.output, Some((0,)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test() {
+        let boxed: DynBox<Tokens, (i32,)> = Default::default();
+        boxed.parse(Vec::new().into_iter());
+    }
+
+    #[test]
+    fn recursive() {
+        // paren: Open paren? Close
+        let paren = Rc::new(RefCell::new(DynBox::<Tokens, ()>::default()));
+        let paren_body = SeqParser::new(
+            SeqParser::new(
+                VoidParser::new(SingleEqualParser::new(Token::Open)),
+                OptionalParser::new(Rc::clone(&paren)),
+            ),
+            VoidParser::new(SingleEqualParser::new(Token::Close)),
+        );
+        paren.borrow_mut().assign(VoidParser::new(paren_body));
+
+        let tokens = vec![Token::Open, Token::Open, Token::Close, Token::Close];
+        let res = paren.parse(tokens.into_iter());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.len(), 0);
+
+        let tokens = vec![Token::Open, Token::Open, Token::Close];
+        let res = paren.parse(tokens.into_iter());
+        assert_eq!(res.output, None);
+    }
+
+    struct CharIndices;
+    impl InputFamily for CharIndices {
+        type Input<'a> = std::str::CharIndices<'a>;
+    }
+
+    #[test]
+    fn borrowed() {
+        let digit =
+            SingleCheckParser::new(|(i, ch): (usize, char)| ch.is_ascii_digit().then_some(i));
+        let boxed: DynBoxFor<CharIndices, (usize,)> = DynBoxFor::new(digit);
+
+        // input borrowed from a local string, not 'static
+        let input = String::from("a1");
+        let mut it = input.char_indices();
+        it.next();
+        let res = boxed.parse(it);
+        assert_eq!(res.output, Some((1,)));
+
+        let res = boxed.parse(input.char_indices());
+        assert_eq!(res.output, None);
+    }
+}
@@ -1,3 +1,5 @@
 pub mod chars;
+pub mod generic;
 pub mod slice_cloned;
 pub mod slice_copied;
+pub mod sync;
@@ -8,14 +8,15 @@ use crate::core::tuple::Tuple;
 
 use crate::leaf::panic::Panic;
 
+type DynSliceParser<Output, T> =
+    dyn for<'a> Parser<std::iter::Cloned<std::slice::Iter<'a, T>>, Output = Output>;
+
 pub struct DynBoxSlice<Output, T>
 where
     Output: Tuple,
     T: Clone,
 {
-    parser: std::boxed::Box<
-        dyn for<'a> Parser<std::iter::Cloned<std::slice::Iter<'a, T>>, Output = Output>,
-    >,
+    parser: std::boxed::Box<DynSliceParser<Output, T>>,
 }
 
 impl<Output, T> DynBoxSlice<Output, T>
@@ -74,9 +75,7 @@ where
     Output: Tuple,
     T: Clone,
 {
-    type Target = std::boxed::Box<
-        dyn for<'a> Parser<std::iter::Cloned<std::slice::Iter<'a, T>>, Output = Output>,
-    >;
+    type Target = std::boxed::Box<DynSliceParser<Output, T>>;
 
     fn deref(&self) -> &Self::Target {
         &self.parser
@@ -111,7 +110,7 @@ mod test {
     #[should_panic]
     fn panic_test2() {
         let boxed: DynBoxSlice<(i32,), i32> = Default::default();
-        boxed.parse((&[1, 2, 3]).iter().cloned());
-        boxed.match_pattern((&[1, 2, 3]).iter().cloned());
+        boxed.parse([1, 2, 3].iter().cloned());
+        boxed.match_pattern([1, 2, 3].iter().cloned());
     }
 }
@@ -8,14 +8,15 @@ use crate::core::tuple::Tuple;
 
 use crate::leaf::panic::Panic;
 
+type DynSliceCopiedParser<Output, T> =
+    dyn for<'a> Parser<std::iter::Copied<std::slice::Iter<'a, T>>, Output = Output>;
+
 pub struct DynBoxSliceCopied<Output, T>
 where
     Output: Tuple,
     T: Copy,
 {
-    parser: std::boxed::Box<
-        dyn for<'a> Parser<std::iter::Copied<std::slice::Iter<'a, T>>, Output = Output>,
-    >,
+    parser: std::boxed::Box<DynSliceCopiedParser<Output, T>>,
 }
 
 impl<Output, T> DynBoxSliceCopied<Output, T>
@@ -75,9 +76,7 @@ where
     Output: Tuple,
     T: Copy,
 {
-    type Target = std::boxed::Box<
-        dyn for<'a> Parser<std::iter::Copied<std::slice::Iter<'a, T>>, Output = Output>,
-    >;
+    type Target = std::boxed::Box<DynSliceCopiedParser<Output, T>>;
 
     fn deref(&self) -> &Self::Target {
         &self.parser
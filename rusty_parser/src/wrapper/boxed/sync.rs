@@ -0,0 +1,475 @@
+//! `Send + Sync` versions of the `DynBox*` parsers.
+//!
+//! These only take parsers which are `Send + Sync` themselves,
+//! so they can be shared across threads, e.g. in a `static` or an [`std::sync::Arc`].
+
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+use crate::core::tuple::Tuple;
+
+use crate::leaf::panic::Panic;
+
+use super::generic::InputFamily;
+
+type DynCharsParserSync<Output> =
+    dyn for<'a> Parser<std::str::Chars<'a>, Output = Output> + Send + Sync;
+
+/// `Send + Sync` version of [`super::chars::DynBoxChars`].
+pub struct DynBoxCharsSync<Output>
+where
+    Output: Tuple,
+{
+    parser: std::boxed::Box<DynCharsParserSync<Output>>,
+}
+
+impl<Output> DynBoxCharsSync<Output>
+where
+    Output: Tuple,
+{
+    pub fn new<ParserType: IntoParser>(parser: ParserType) -> Self
+    where
+        ParserType::Into:
+            for<'a> Parser<std::str::Chars<'a>, Output = Output> + Send + Sync + 'static,
+    {
+        Self {
+            parser: std::boxed::Box::new(parser.into_parser()),
+        }
+    }
+    pub fn assign<ParserType: IntoParser>(&mut self, parser: ParserType)
+    where
+        ParserType::Into:
+            for<'a> Parser<std::str::Chars<'a>, Output = Output> + Send + Sync + 'static,
+    {
+        self.parser = std::boxed::Box::new(parser.into_parser());
+    }
+}
+
+/// default to dummy parser that always panic
+impl<Output: Tuple + 'static> Default for DynBoxCharsSync<Output> {
+    fn default() -> Self {
+        Self::new(Panic::new())
+    }
+}
+
+impl<'a, Output> Parser<std::str::Chars<'a>> for DynBoxCharsSync<Output>
+where
+    Output: Tuple,
+{
+    type Output = Output;
+
+    fn parse(&self, it: std::str::Chars<'a>) -> ParseResult<Self::Output, std::str::Chars<'a>> {
+        self.parser.parse(it)
+    }
+
+    fn match_pattern(&self, it: std::str::Chars<'a>) -> ParseResult<(), std::str::Chars<'a>> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<Output> Deref for DynBoxCharsSync<Output>
+where
+    Output: Tuple,
+{
+    type Target = std::boxed::Box<DynCharsParserSync<Output>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parser
+    }
+}
+impl<Output> DerefMut for DynBoxCharsSync<Output>
+where
+    Output: Tuple,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parser
+    }
+}
+impl<Output> IntoParser for DynBoxCharsSync<Output>
+where
+    Output: Tuple,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+type DynSliceParserSync<Output, T> =
+    dyn for<'a> Parser<std::iter::Cloned<std::slice::Iter<'a, T>>, Output = Output> + Send + Sync;
+
+/// `Send + Sync` version of [`super::slice_cloned::DynBoxSlice`].
+pub struct DynBoxSliceSync<Output, T>
+where
+    Output: Tuple,
+    T: Clone,
+{
+    parser: std::boxed::Box<DynSliceParserSync<Output, T>>,
+}
+
+impl<Output, T> DynBoxSliceSync<Output, T>
+where
+    Output: Tuple,
+    T: Clone,
+{
+    pub fn new<ParserType: IntoParser>(parser: ParserType) -> Self
+    where
+        ParserType::Into: for<'a> Parser<std::iter::Cloned<std::slice::Iter<'a, T>>, Output = Output>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            parser: std::boxed::Box::new(parser.into_parser()),
+        }
+    }
+    pub fn assign<ParserType: IntoParser>(&mut self, parser: ParserType)
+    where
+        ParserType::Into: for<'a> Parser<std::iter::Cloned<std::slice::Iter<'a, T>>, Output = Output>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.parser = std::boxed::Box::new(parser.into_parser());
+    }
+}
+
+/// default to dummy parser that always panic
+impl<Output: Tuple + 'static, T: Clone + 'static> Default for DynBoxSliceSync<Output, T> {
+    fn default() -> Self {
+        Self::new(Panic::new())
+    }
+}
+
+impl<'a, Output, T> Parser<std::iter::Cloned<std::slice::Iter<'a, T>>>
+    for DynBoxSliceSync<Output, T>
+where
+    Output: Tuple,
+    T: Clone,
+{
+    type Output = Output;
+
+    fn parse(
+        &self,
+        it: std::iter::Cloned<std::slice::Iter<'a, T>>,
+    ) -> ParseResult<Self::Output, std::iter::Cloned<std::slice::Iter<'a, T>>> {
+        self.parser.parse(it)
+    }
+
+    fn match_pattern(
+        &self,
+        it: std::iter::Cloned<std::slice::Iter<'a, T>>,
+    ) -> ParseResult<(), std::iter::Cloned<std::slice::Iter<'a, T>>> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<Output, T> Deref for DynBoxSliceSync<Output, T>
+where
+    Output: Tuple,
+    T: Clone,
+{
+    type Target = std::boxed::Box<DynSliceParserSync<Output, T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parser
+    }
+}
+impl<Output, T> DerefMut for DynBoxSliceSync<Output, T>
+where
+    Output: Tuple,
+    T: Clone,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parser
+    }
+}
+impl<Output, T> IntoParser for DynBoxSliceSync<Output, T>
+where
+    Output: Tuple,
+    T: Clone,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+type DynSliceCopiedParserSync<Output, T> =
+    dyn for<'a> Parser<std::iter::Copied<std::slice::Iter<'a, T>>, Output = Output> + Send + Sync;
+
+/// `Send + Sync` version of [`super::slice_copied::DynBoxSliceCopied`].
+pub struct DynBoxSliceCopiedSync<Output, T>
+where
+    Output: Tuple,
+    T: Copy,
+{
+    parser: std::boxed::Box<DynSliceCopiedParserSync<Output, T>>,
+}
+
+impl<Output, T> DynBoxSliceCopiedSync<Output, T>
+where
+    Output: Tuple,
+    T: Copy,
+{
+    pub fn new<ParserType: IntoParser>(parser: ParserType) -> Self
+    where
+        ParserType::Into: for<'a> Parser<std::iter::Copied<std::slice::Iter<'a, T>>, Output = Output>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            parser: std::boxed::Box::new(parser.into_parser()),
+        }
+    }
+    pub fn assign<ParserType: IntoParser>(&mut self, parser: ParserType)
+    where
+        ParserType::Into: for<'a> Parser<std::iter::Copied<std::slice::Iter<'a, T>>, Output = Output>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.parser = std::boxed::Box::new(parser.into_parser());
+    }
+}
+
+/// default to dummy parser that always panic
+impl<Output: Tuple + 'static, T: Copy + 'static> Default for DynBoxSliceCopiedSync<Output, T> {
+    fn default() -> Self {
+        Self::new(Panic::new())
+    }
+}
+
+impl<'a, Output, T> Parser<std::iter::Copied<std::slice::Iter<'a, T>>>
+    for DynBoxSliceCopiedSync<Output, T>
+where
+    Output: Tuple,
+    T: Copy,
+{
+    type Output = Output;
+
+    fn parse(
+        &self,
+        it: std::iter::Copied<std::slice::Iter<'a, T>>,
+    ) -> ParseResult<Self::Output, std::iter::Copied<std::slice::Iter<'a, T>>> {
+        self.parser.parse(it)
+    }
+
+    fn match_pattern(
+        &self,
+        it: std::iter::Copied<std::slice::Iter<'a, T>>,
+    ) -> ParseResult<(), std::iter::Copied<std::slice::Iter<'a, T>>> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<Output, T> Deref for DynBoxSliceCopiedSync<Output, T>
+where
+    Output: Tuple,
+    T: Copy,
+{
+    type Target = std::boxed::Box<DynSliceCopiedParserSync<Output, T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parser
+    }
+}
+impl<Output, T> DerefMut for DynBoxSliceCopiedSync<Output, T>
+where
+    Output: Tuple,
+    T: Copy,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parser
+    }
+}
+impl<Output, T> IntoParser for DynBoxSliceCopiedSync<Output, T>
+where
+    Output: Tuple,
+    T: Copy,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+type DynParserSync<It, Output> = dyn Parser<It, Output = Output> + Send + Sync;
+
+/// `Send + Sync` version of [`super::generic::DynBox`].
+pub struct DynBoxSync<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    parser: std::boxed::Box<DynParserSync<It, Output>>,
+}
+
+impl<It, Output> DynBoxSync<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    pub fn new<ParserType: IntoParser>(parser: ParserType) -> Self
+    where
+        ParserType::Into: Parser<It, Output = Output> + Send + Sync + 'static,
+    {
+        Self {
+            parser: std::boxed::Box::new(parser.into_parser()),
+        }
+    }
+    pub fn assign<ParserType: IntoParser>(&mut self, parser: ParserType)
+    where
+        ParserType::Into: Parser<It, Output = Output> + Send + Sync + 'static,
+    {
+        self.parser = std::boxed::Box::new(parser.into_parser());
+    }
+}
+
+/// default to dummy parser that always panic
+impl<It: InputIteratorTrait, Output: Tuple + 'static> Default for DynBoxSync<It, Output> {
+    fn default() -> Self {
+        Self::new(Panic::new())
+    }
+}
+
+impl<It, Output> Parser<It> for DynBoxSync<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    type Output = Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.parser.parse(it)
+    }
+
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<It, Output> Deref for DynBoxSync<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    type Target = std::boxed::Box<DynParserSync<It, Output>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parser
+    }
+}
+impl<It, Output> DerefMut for DynBoxSync<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parser
+    }
+}
+impl<It, Output> IntoParser for DynBoxSync<It, Output>
+where
+    It: InputIteratorTrait,
+    Output: Tuple,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+type DynFamilyParserSync<Family, Output> =
+    dyn for<'a> Parser<<Family as InputFamily>::Input<'a>, Output = Output> + Send + Sync;
+
+/// `Send + Sync` version of [`super::generic::DynBoxFor`].
+pub struct DynBoxForSync<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    parser: std::boxed::Box<DynFamilyParserSync<Family, Output>>,
+}
+
+impl<Family, Output> DynBoxForSync<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    pub fn new<ParserType: IntoParser>(parser: ParserType) -> Self
+    where
+        ParserType::Into:
+            for<'a> Parser<Family::Input<'a>, Output = Output> + Send + Sync + 'static,
+    {
+        Self {
+            parser: std::boxed::Box::new(parser.into_parser()),
+        }
+    }
+    pub fn assign<ParserType: IntoParser>(&mut self, parser: ParserType)
+    where
+        ParserType::Into:
+            for<'a> Parser<Family::Input<'a>, Output = Output> + Send + Sync + 'static,
+    {
+        self.parser = std::boxed::Box::new(parser.into_parser());
+    }
+}
+
+/// default to dummy parser that always panic
+impl<Family: InputFamily, Output: Tuple + 'static> Default for DynBoxForSync<Family, Output> {
+    fn default() -> Self {
+        Self::new(Panic::new())
+    }
+}
+
+impl<'a, Family, Output> Parser<Family::Input<'a>> for DynBoxForSync<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    type Output = Output;
+
+    fn parse(&self, it: Family::Input<'a>) -> ParseResult<Self::Output, Family::Input<'a>> {
+        self.parser.parse(it)
+    }
+
+    fn match_pattern(&self, it: Family::Input<'a>) -> ParseResult<(), Family::Input<'a>> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<Family, Output> Deref for DynBoxForSync<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    type Target = std::boxed::Box<DynFamilyParserSync<Family, Output>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parser
+    }
+}
+impl<Family, Output> DerefMut for DynBoxForSync<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parser
+    }
+}
+impl<Family, Output> IntoParser for DynBoxForSync<Family, Output>
+where
+    Family: InputFamily,
+    Output: Tuple,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
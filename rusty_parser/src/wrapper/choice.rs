@@ -0,0 +1,242 @@
+use crate::core::error::{self, ParseError};
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::partial;
+use crate::core::result::ParseResult;
+
+// `or!` over a runtime list of parsers; alternatives are tried in order
+#[derive(Debug, Clone)]
+pub struct Choice<ParserType> {
+    parsers: Vec<ParserType>,
+}
+
+impl<ParserType> Choice<ParserType> {
+    pub fn new() -> Self {
+        Self {
+            parsers: Vec::new(),
+        }
+    }
+
+    /// Add an alternative, tried after the others.
+    pub fn push(&mut self, parser: ParserType) {
+        self.parsers.push(parser);
+    }
+    /// Add an alternative at `index`; panics if `index > len`.
+    pub fn insert(&mut self, index: usize, parser: ParserType) {
+        self.parsers.insert(index, parser);
+    }
+    /// Remove the alternative at `index`; panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> ParserType {
+        self.parsers.remove(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.parsers.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parsers.is_empty()
+    }
+    pub fn parsers(&self) -> &[ParserType] {
+        &self.parsers
+    }
+}
+
+impl<ParserType> Default for Choice<ParserType> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<ParserType> From<Vec<ParserType>> for Choice<ParserType> {
+    fn from(parsers: Vec<ParserType>) -> Self {
+        Self { parsers }
+    }
+}
+
+impl<ParserType> FromIterator<ParserType> for Choice<ParserType> {
+    fn from_iter<I: IntoIterator<Item = ParserType>>(iter: I) -> Self {
+        Self {
+            parsers: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<ParserType, It> Parser<It> for Choice<ParserType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let mut error = None;
+        let mut incomplete = None;
+        for parser in &self.parsers {
+            let res = parser.parse(it.clone());
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
+            if let Some(val) = res.output {
+                return ParseResult {
+                    output: Some(val),
+                    it: res.it,
+                    error,
+                    recovered: res.recovered,
+                    committed: false,
+                    incomplete,
+                };
+            }
+            if res.committed {
+                // hard error after cut; do not try the other alternatives
+                return ParseResult {
+                    output: None,
+                    it: res.it,
+                    error,
+                    recovered: Vec::new(),
+                    committed: true,
+                    incomplete,
+                };
+            }
+        }
+
+        // no alternatives; fails like `fail()`
+        let error = error.or_else(|| Some(ParseError::new(it.clone())));
+        ParseResult {
+            output: None,
+            it,
+            error,
+            recovered: Vec::new(),
+            committed: false,
+            incomplete,
+        }
+    }
+
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let mut error = None;
+        let mut incomplete = None;
+        for parser in &self.parsers {
+            let res = parser.match_pattern(it.clone());
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
+            if res.output.is_some() {
+                return ParseResult {
+                    output: Some(()),
+                    it: res.it,
+                    error,
+                    recovered: res.recovered,
+                    committed: false,
+                    incomplete,
+                };
+            }
+            if res.committed {
+                // hard error after cut; do not try the other alternatives
+                return ParseResult {
+                    output: None,
+                    it: res.it,
+                    error,
+                    recovered: Vec::new(),
+                    committed: true,
+                    incomplete,
+                };
+            }
+        }
+
+        // no alternatives; fails like `fail()`
+        let error = error.or_else(|| Some(ParseError::new(it.clone())));
+        ParseResult {
+            output: None,
+            it,
+            error,
+            recovered: Vec::new(),
+            committed: false,
+            incomplete,
+        }
+    }
+}
+
+impl<ParserType> IntoParser for Choice<ParserType> {
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::cut::CutParser;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::leaf::stringeq::StringEqualParser;
+    use crate::wrapper::boxed::chars::DynBoxChars;
+    use crate::wrapper::output::OutputParser;
+    use crate::wrapper::seq::SeqParser;
+
+    #[test]
+    fn success1() {
+        // keywords configured at runtime
+        let mut keywords: Choice<DynBoxChars<(usize,)>> = ["let", "fn"]
+            .into_iter()
+            .enumerate()
+            .map(|(id, keyword)| {
+                DynBoxChars::new(OutputParser::new(
+                    StringEqualParser::new(keyword.to_string()),
+                    id,
+                ))
+            })
+            .collect();
+
+        let res = keywords.parse("fn main".chars());
+        assert_eq!(res.output, Some((1,)));
+        assert_eq!(res.it.as_str(), " main");
+
+        let res = keywords.parse("if".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "if");
+        assert_eq!(res.error.unwrap().to_string(), "expected \"let\" or \"fn\"");
+
+        keywords.insert(
+            0,
+            DynBoxChars::new(OutputParser::new(
+                StringEqualParser::new("if".to_string()),
+                2,
+            )),
+        );
+        let res = keywords.parse("if".chars());
+        assert_eq!(res.output, Some((2,)));
+
+        keywords.remove(0);
+        let res = keywords.match_pattern("if".chars());
+        assert_eq!(res.output, None);
+        let res = keywords.match_pattern("let".chars());
+        assert_eq!(res.output, Some(()));
+    }
+
+    #[test]
+    fn empty() {
+        let choice: Choice<SingleEqualParser<char>> = Choice::new();
+        let res = choice.parse("a".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.error.unwrap().to_string(), "unexpected input");
+    }
+
+    #[test]
+    fn committed() {
+        let mut choice = Choice::new();
+        choice.push(DynBoxChars::new(SeqParser::new(
+            SingleEqualParser::new('a'),
+            SeqParser::new(CutParser::new(), SingleEqualParser::new('b')),
+        )));
+        choice.push(DynBoxChars::new(SeqParser::new(
+            SingleEqualParser::new('a'),
+            SingleEqualParser::new('c'),
+        )));
+
+        let res = choice.parse("ab".chars());
+        assert_eq!(res.output, Some(('a', 'b')));
+
+        // the second alternative is not tried after cut
+        let res = choice.parse("ac".chars());
+        assert_eq!(res.output, None);
+        assert!(res.committed);
+    }
+}
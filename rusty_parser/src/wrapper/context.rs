@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+use crate::core::tuple::Tuple;
+
+#[derive(Debug, Clone)]
+pub struct ContextParser<ParserType> {
+    parser: ParserType,
+    context: Cow<'static, str>,
+}
+
+impl<ParserType> ContextParser<ParserType> {
+    pub fn new(parser: ParserType, context: Cow<'static, str>) -> Self {
+        Self { parser, context }
+    }
+
+    // push context frame to the error reported from inside of this parser;
+    // only on failure past `i0`, the error of a successful parse
+    // or of a parser failed at its start ( e.g. an alternative not taken ) is left as is
+    fn push_context<Output, It>(&self, i0: &It, res: &mut ParseResult<Output, It>)
+    where
+        Output: Tuple,
+        It: InputIteratorTrait,
+    {
+        if res.output.is_some() {
+            return;
+        }
+        match &mut res.error {
+            Some(error) if !error.is_at(i0) => error.context.push(self.context.clone()),
+            _ => {}
+        }
+    }
+}
+
+impl<ParserType, It> Parser<It> for ContextParser<ParserType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let mut res = self.parser.parse(it);
+        self.push_context(&i0, &mut res);
+        res
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let i0 = it.clone();
+        let mut res = self.parser.match_pattern(it);
+        self.push_context(&i0, &mut res);
+        res
+    }
+}
+
+impl<ParserType> IntoParser for ContextParser<ParserType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::repeat::RepeatParser;
+    use crate::wrapper::seq::SeqParser;
+
+    #[test]
+    fn fail1() {
+        let member = SeqParser::new('k'.into_parser(), ':'.into_parser());
+        let member = ContextParser::new(member, "in member".into());
+        let object = SeqParser::new('{'.into_parser(), member);
+        let object = ContextParser::new(object, "in object".into());
+
+        let res = object.parse("{k=".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "{k=");
+
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "=");
+        assert_eq!(error.context, vec!["in member", "in object"]);
+        assert_eq!(error.to_string(), "in object → in member: expected ':'");
+    }
+
+    #[test]
+    fn success1() {
+        let parser = ContextParser::new('a'.into_parser(), "in a".into());
+
+        let res = parser.parse("a".chars());
+        assert_eq!(res.output, Some(('a',)));
+        assert!(res.error.is_none());
+
+        // the furthest error of a successful parse gets no frame
+        let digits = ContextParser::new(
+            RepeatParser::from(SingleRangeParser::from('0'..='9'), 1..),
+            "in number".into(),
+        );
+        let res = digits.parse("12;".chars());
+        assert_eq!(res.output, Some((vec!['1', '2'],)));
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), ";");
+        assert!(error.context.is_empty());
+    }
+
+    #[test]
+    fn fail_at_start() {
+        // not entered; the error is about the input, not about the inside of this parser
+        let parser = ContextParser::new(
+            SeqParser::new('['.into_parser(), ']'.into_parser()),
+            "in array".into(),
+        );
+        let res = parser.parse("x".chars());
+        let error = res.error.unwrap();
+        assert!(error.context.is_empty());
+        assert_eq!(error.to_string(), "expected '['");
+    }
+}
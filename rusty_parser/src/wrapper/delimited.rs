@@ -0,0 +1,94 @@
+use crate::wrapper::seq::SeqParser;
+use crate::wrapper::void::VoidParser;
+
+// `parser` between `open` and `close`, discarding their output;
+// used by `between` and `padded`
+pub type DelimitedParser<OpenType, ParserType, CloseType> =
+    SeqParser<SeqParser<VoidParser<OpenType>, ParserType>, VoidParser<CloseType>>;
+
+#[cfg(test)]
+mod test {
+    use crate::core::into_parser::IntoParser;
+    use crate::core::parser::Parser;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::leaf::singlerange::SingleRangeParser;
+
+    #[test]
+    fn between() {
+        let parser = SingleRangeParser::from('0'..='9').between('(', ')');
+
+        let res = parser.parse("(1);".chars());
+        assert_eq!(res.output, Some(('1',)));
+        assert_eq!(res.it.as_str(), ";");
+
+        // missing close
+        let res = parser.parse("(1;".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "(1;");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), ";");
+        assert_eq!(error.to_string(), "expected ')'");
+
+        // missing open
+        let res = parser.parse("1)".chars());
+        assert_eq!(res.output, None);
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "1)");
+        assert_eq!(error.to_string(), "expected '('");
+
+        let res = parser.match_pattern("(1)".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+
+        // labels of the delimiters are kept
+        let parser = 'x'.between('('.label("open paren"), ')'.label("close paren"));
+        let res = parser.parse("x)".chars());
+        assert_eq!(res.error.unwrap().to_string(), "expected open paren");
+        let res = parser.parse("(x".chars());
+        assert_eq!(res.error.unwrap().to_string(), "expected close paren");
+    }
+
+    #[test]
+    fn padded() {
+        let ws = SingleEqualParser::new(' ').repeat(0..);
+        let parser = SingleRangeParser::from('0'..='9').label("digit").padded(ws);
+
+        let res = parser.parse("  1 ,".chars());
+        assert_eq!(res.output, Some(('1',)));
+        assert_eq!(res.it.as_str(), ",");
+
+        let res = parser.parse("1".chars());
+        assert_eq!(res.output, Some(('1',)));
+        assert_eq!(res.it.as_str(), "");
+
+        // error after the leading whitespace
+        let res = parser.parse("  a".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "  a");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "a");
+        assert_eq!(error.to_string(), "expected digit");
+    }
+
+    #[test]
+    fn preceded_by() {
+        let parser = SingleRangeParser::from('0'..='9')
+            .label("digit")
+            .preceded_by('-');
+
+        let res = parser.parse("-1".chars());
+        assert_eq!(res.output, Some(('1',)));
+        assert_eq!(res.it.as_str(), "");
+
+        let res = parser.parse("-a".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "-a");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "a");
+        assert_eq!(error.to_string(), "expected digit");
+
+        let res = parser.parse("1".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.error.unwrap().to_string(), "expected '-'");
+    }
+}
@@ -0,0 +1,132 @@
+use std::borrow::Cow;
+
+use crate::core::error::{Expected, ParseError};
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+
+#[derive(Debug, Clone)]
+pub struct LabelParser<ParserType> {
+    parser: ParserType,
+    label: Cow<'static, str>,
+}
+
+impl<ParserType> LabelParser<ParserType> {
+    pub fn new(parser: ParserType, label: Cow<'static, str>) -> Self {
+        Self { parser, label }
+    }
+
+    // if the inner parser failed without consuming any input,
+    // replace what it expected with the label
+    fn relabel<It>(&self, i0: &It, error: Option<ParseError<It>>) -> Option<ParseError<It>>
+    where
+        It: InputIteratorTrait,
+    {
+        match error {
+            Some(error) if error.is_at(i0) => Some(ParseError::expected(
+                error.it,
+                Expected::Label(self.label.clone()),
+            )),
+            error => error,
+        }
+    }
+}
+
+impl<ParserType, It> Parser<It> for LabelParser<ParserType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let res = self.parser.parse(it);
+        ParseResult {
+            output: res.output,
+            it: res.it,
+            error: self.relabel(&i0, res.error),
+            recovered: res.recovered,
+            committed: res.committed,
+            incomplete: res.incomplete,
+        }
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let i0 = it.clone();
+        let res = self.parser.match_pattern(it);
+        ParseResult {
+            error: self.relabel(&i0, res.error),
+            ..res
+        }
+    }
+}
+
+impl<ParserType> IntoParser for LabelParser<ParserType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::or::OrParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::wrapper::void::VoidParser;
+
+    #[test]
+    fn fail1() {
+        let digit_parser = SingleRangeParser::from('0'..='9');
+        let hex_parser = OrParser::new(digit_parser, SingleRangeParser::from('a'..='f'));
+        let hex_parser = LabelParser::new(hex_parser, "hex digit".into());
+
+        let res = hex_parser.parse("xyz".chars());
+        assert_eq!(res.output, None);
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "xyz");
+        assert_eq!(error.to_string(), "expected hex digit");
+
+        // errors are not built under `match_pattern`
+        let res = hex_parser.match_pattern("xyz".chars());
+        assert_eq!(res.output, None);
+        assert!(res.error.is_none());
+    }
+
+    #[test]
+    fn fail2() {
+        // failed after consuming some input; keep the inner error
+        let pair_parser = SeqParser::new('('.into_parser(), ')'.into_parser());
+        let pair_parser = LabelParser::new(pair_parser, "pair".into());
+
+        let res = pair_parser.parse("(x".chars());
+        assert_eq!(res.output, None);
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "x");
+        assert_eq!(error.to_string(), "expected ')'");
+    }
+
+    #[test]
+    fn void() {
+        // the label shows through a combinator matching its inner parser
+        let number = SingleRangeParser::from('0'..='9')
+            .repeat(1..)
+            .label("number");
+        let parser = VoidParser::new(SeqParser::new(
+            SeqParser::new('('.into_parser(), number),
+            ')'.into_parser(),
+        ));
+
+        let res = parser.parse("(x)".chars());
+        assert_eq!(res.output, None);
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "x)");
+        assert_eq!(error.to_string(), "expected number");
+
+        let res = parser.parse("x".chars());
+        assert_eq!(res.error.unwrap().to_string(), "expected '('");
+    }
+}
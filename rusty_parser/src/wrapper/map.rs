@@ -30,19 +30,9 @@ where
     type Output = (ClosureType::Output,);
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        let res = self.parser.parse(it);
-        if let Some(val) = res.output {
-            let callback_res = (self.callback).map(val);
-            ParseResult {
-                output: Some((callback_res,)),
-                it: res.it,
-            }
-        } else {
-            ParseResult {
-                output: None,
-                it: res.it,
-            }
-        }
+        self.parser
+            .parse(it)
+            .map_output(|val| ((self.callback).map(val),))
     }
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
         self.parser.match_pattern(it)
@@ -1,18 +1,37 @@
+pub mod and_then;
+pub mod arced;
 pub mod boxed;
+pub mod choice;
+pub mod context;
+pub mod delimited;
 pub mod inspect;
+pub mod label;
 pub mod map;
+pub mod mutexed;
 pub mod not;
 pub mod notconsume;
+pub mod oncelocked;
 pub mod option;
 pub mod or;
 pub mod or_else;
 pub mod output;
+pub mod permutation;
+pub mod pratt;
 pub mod rced;
+pub mod recognize;
+pub mod recover;
+pub mod recursive;
 pub mod reduce;
 pub mod refcelled;
 pub mod repeat;
+pub mod repeat_until;
+pub mod rwlocked;
+pub mod sep_by;
 pub mod seq;
 pub mod slice;
+pub mod spanned;
+pub mod try_map;
+pub mod verify;
 pub mod void;
 
 pub(crate) mod tupleutils;
@@ -0,0 +1,61 @@
+use std::sync::{Mutex, PoisonError};
+
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+
+// The lock is held while parsing; a recursive parser must not lock the same `Mutex` again,
+// use `recursive` or `OnceLock` for recursion instead.
+// Parsing does not modify the parser, so a lock poisoned by a panic is still usable.
+
+impl<ParserType, It> Parser<It> for Mutex<ParserType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .parse(it)
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .match_pattern(it)
+    }
+}
+impl<ParserType> IntoParser for Mutex<ParserType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{leaf::singlerange::SingleRangeParser, wrapper::boxed::sync::DynBoxCharsSync};
+
+    #[test]
+    fn success1() {
+        let digit_parser = SingleRangeParser::from('0'..='9');
+        let boxed: DynBoxCharsSync<(char,)> = DynBoxCharsSync::new(digit_parser);
+        let locked = Mutex::new(boxed);
+
+        let res = locked.parse("123abc".chars());
+        assert_eq!(res.output, Some(('1',)));
+        assert_eq!(res.it.as_str(), "23abc");
+
+        locked
+            .lock()
+            .unwrap()
+            .assign(SingleRangeParser::from('a'..='z'));
+        let res = locked.parse(res.it);
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "23abc");
+    }
+}
@@ -1,6 +1,8 @@
+use crate::core::error::ParseError;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
 
 #[derive(Debug, Clone, Copy)]
@@ -25,25 +27,37 @@ where
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let i0 = it.clone();
-        if self.parser_b.match_pattern(it).output.is_some() {
+        let res_b = self.parser_b.match_pattern(it);
+        if res_b.output.is_some() {
             ParseResult {
-                output: None,
-                it: i0,
+                incomplete: res_b.incomplete,
+                ..ParseResult::failure(i0.clone(), ParseError::new(i0))
             }
         } else {
-            self.parser_a.parse(i0)
+            // `parser_b` may match with more input
+            let res = self.parser_a.parse(i0);
+            ParseResult {
+                incomplete: partial::merge(res_b.incomplete, res.incomplete),
+                ..res
+            }
         }
     }
 
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
         let i0 = it.clone();
-        if self.parser_b.match_pattern(it).output.is_some() {
+        let res_b = self.parser_b.match_pattern(it);
+        if res_b.output.is_some() {
             ParseResult {
-                output: None,
-                it: i0,
+                incomplete: res_b.incomplete,
+                ..ParseResult::failure(i0.clone(), ParseError::new(i0))
             }
         } else {
-            self.parser_a.match_pattern(i0)
+            // `parser_b` may match with more input
+            let res = self.parser_a.match_pattern(i0);
+            ParseResult {
+                incomplete: partial::merge(res_b.incomplete, res.incomplete),
+                ..res
+            }
         }
     }
 }
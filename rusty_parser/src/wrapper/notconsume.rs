@@ -23,30 +23,34 @@ where
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let i0 = it.clone();
-        if let Some(val) = self.parser.parse(it).output {
+        let res = self.parser.parse(it);
+        if let Some(val) = res.output {
             ParseResult {
                 output: Some(val),
                 it: i0,
+                error: res.error,
+                recovered: Vec::new(),
+                committed: res.committed,
+                incomplete: res.incomplete,
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            res.rewind(i0)
         }
     }
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
         let i0 = it.clone();
-        if self.parser.match_pattern(it).output.is_some() {
+        let res = self.parser.match_pattern(it);
+        if res.output.is_some() {
             ParseResult {
                 output: Some(()),
                 it: i0,
+                error: res.error,
+                recovered: Vec::new(),
+                committed: res.committed,
+                incomplete: res.incomplete,
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            res.rewind(i0)
         }
     }
 }
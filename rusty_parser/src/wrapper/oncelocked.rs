@@ -0,0 +1,122 @@
+#[cfg(feature = "lazy-lock")]
+use std::sync::LazyLock;
+use std::sync::OnceLock;
+
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+
+// Lazily initialized parsers, without locking once initialized.
+// `OnceLock` is set after the parsers referring to it are built, so it can be recursive.
+
+/// panics if the parser is not set yet
+impl<ParserType, It> Parser<It> for OnceLock<ParserType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.get().expect("parser in OnceLock is not set").parse(it)
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.get()
+            .expect("parser in OnceLock is not set")
+            .match_pattern(it)
+    }
+}
+impl<ParserType> IntoParser for OnceLock<ParserType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+/// builds the parser on first use
+#[cfg(feature = "lazy-lock")]
+impl<ParserType, F, It> Parser<It> for LazyLock<ParserType, F>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+    F: FnOnce() -> ParserType,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        LazyLock::force(self).parse(it)
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        LazyLock::force(self).match_pattern(it)
+    }
+}
+#[cfg(feature = "lazy-lock")]
+impl<ParserType, F> IntoParser for LazyLock<ParserType, F> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::wrapper::boxed::sync::DynBoxCharsSync;
+    use crate::wrapper::option::OptionalParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::wrapper::void::VoidParser;
+
+    // paren: '(' paren? ')'
+    static PAREN: OnceLock<Arc<OnceLock<DynBoxCharsSync<()>>>> = OnceLock::new();
+
+    fn paren() -> &'static Arc<OnceLock<DynBoxCharsSync<()>>> {
+        PAREN.get_or_init(|| {
+            let paren = Arc::new(OnceLock::new());
+            let paren_body = SeqParser::new(
+                SeqParser::new(
+                    VoidParser::new(SingleEqualParser::new('(')),
+                    OptionalParser::new(Arc::clone(&paren)),
+                ),
+                VoidParser::new(SingleEqualParser::new(')')),
+            );
+            let _ = paren.set(DynBoxCharsSync::new(VoidParser::new(paren_body)));
+            paren
+        })
+    }
+
+    #[test]
+    fn recursive() {
+        let threads: Vec<_> = ["(())", "(()", "()"]
+            .into_iter()
+            .map(|input| std::thread::spawn(move || paren().parse(input.chars()).output))
+            .collect();
+        let res: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+        assert_eq!(res, vec![Some(()), None, Some(())]);
+    }
+
+    #[cfg(feature = "lazy-lock")]
+    #[test]
+    fn lazy() {
+        static ZERO: LazyLock<DynBoxCharsSync<()>> = LazyLock::new(|| {
+            DynBoxCharsSync::new(VoidParser::new(OptionalParser::new(
+                SingleEqualParser::new('0'),
+            )))
+        });
+        let res = ZERO.parse("0".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+    }
+
+    #[test]
+    #[should_panic]
+    fn unset() {
+        let parser: OnceLock<DynBoxCharsSync<(char,)>> = OnceLock::new();
+        parser.parse("1".chars());
+    }
+}
@@ -27,10 +27,25 @@ where
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let res = self.parser.parse(it);
+        if res.output.is_none() && res.committed {
+            // hard error after cut; propagate instead of falling back
+            return ParseResult {
+                output: None,
+                it: res.it,
+                error: res.error,
+                recovered: Vec::new(),
+                committed: true,
+                incomplete: res.incomplete,
+            };
+        }
         if let Some(val) = res.output {
             ParseResult {
                 output: Some((val.make_some(),)),
                 it: res.it,
+                error: res.error,
+                recovered: res.recovered,
+                committed: false,
+                incomplete: res.incomplete,
             }
         } else {
             ParseResult {
@@ -38,14 +53,26 @@ where
                     <<ParserType as Parser<It>>::Output as OptionOutputSpecialize>::make_none(),
                 )),
                 it: res.it,
+                error: res.error,
+                recovered: res.recovered,
+                committed: false,
+                incomplete: res.incomplete,
             }
         }
     }
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
         let res = self.parser.match_pattern(it);
+        if res.output.is_none() && res.committed {
+            // hard error after cut; propagate instead of falling back
+            return res;
+        }
         ParseResult {
             output: Some(()),
             it: res.it,
+            error: res.error,
+            recovered: res.recovered,
+            committed: false,
+            incomplete: res.incomplete,
         }
     }
 }
@@ -87,23 +114,50 @@ where
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let res = self.parser.parse(it);
+        if res.output.is_none() && res.committed {
+            // hard error after cut; propagate instead of falling back
+            return ParseResult {
+                output: None,
+                it: res.it,
+                error: res.error,
+                recovered: Vec::new(),
+                committed: true,
+                incomplete: res.incomplete,
+            };
+        }
         if let Some(val) = res.output {
             ParseResult {
                 output: Some(val),
                 it: res.it,
+                error: res.error,
+                recovered: res.recovered,
+                committed: false,
+                incomplete: res.incomplete,
             }
         } else {
             ParseResult {
                 output: Some(self.output.clone().wrap()),
                 it: res.it,
+                error: res.error,
+                recovered: res.recovered,
+                committed: false,
+                incomplete: res.incomplete,
             }
         }
     }
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
         let res = self.parser.match_pattern(it);
+        if res.output.is_none() && res.committed {
+            // hard error after cut; propagate instead of falling back
+            return res;
+        }
         ParseResult {
             output: Some(()),
             it: res.it,
+            error: res.error,
+            recovered: res.recovered,
+            committed: false,
+            incomplete: res.incomplete,
         }
     }
 }
@@ -1,6 +1,8 @@
+use crate::core::error;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
 
 #[derive(Debug, Clone, Copy)]
@@ -24,46 +26,78 @@ where
     type Output = <ParserA as Parser<It>>::Output;
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        let res = self.parser_a.parse(it);
-        if let Some(val) = res.output {
+        let res_a = self.parser_a.parse(it);
+        if res_a.output.is_none() && res_a.committed {
+            // hard error after cut; do not try the other alternative
+            return res_a;
+        }
+        if let Some(val) = res_a.output {
             return ParseResult {
                 output: Some(val),
-                it: res.it,
+                it: res_a.it,
+                error: res_a.error,
+                recovered: res_a.recovered,
+                committed: false,
+                incomplete: res_a.incomplete,
             };
         }
-        let res = self.parser_b.parse(res.it);
-        if let Some(val) = res.output {
+        let res_b = self.parser_b.parse(res_a.it);
+        if let Some(val) = res_b.output {
             return ParseResult {
                 output: Some(val),
-                it: res.it,
+                it: res_b.it,
+                error: error::merge(res_a.error, res_b.error),
+                recovered: res_b.recovered,
+                committed: false,
+                incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
             };
         }
 
         ParseResult {
             output: None,
-            it: res.it,
+            it: res_b.it,
+            error: error::merge(res_a.error, res_b.error),
+            recovered: Vec::new(),
+            committed: res_b.committed,
+            incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
         }
     }
 
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
-        let res = self.parser_a.match_pattern(it);
-        if res.output.is_some() {
+        let res_a = self.parser_a.match_pattern(it);
+        if res_a.output.is_none() && res_a.committed {
+            // hard error after cut; do not try the other alternative
+            return res_a;
+        }
+        if res_a.output.is_some() {
             return ParseResult {
                 output: Some(()),
-                it: res.it,
+                it: res_a.it,
+                error: res_a.error,
+                recovered: res_a.recovered,
+                committed: false,
+                incomplete: res_a.incomplete,
             };
         }
-        let res = self.parser_b.parse(res.it);
-        if res.output.is_some() {
+        let res_b = self.parser_b.match_pattern(res_a.it);
+        if res_b.output.is_some() {
             return ParseResult {
                 output: Some(()),
-                it: res.it,
+                it: res_b.it,
+                error: error::merge(res_a.error, res_b.error),
+                recovered: res_b.recovered,
+                committed: false,
+                incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
             };
         }
 
         ParseResult {
             output: None,
-            it: res.it,
+            it: res_b.it,
+            error: error::merge(res_a.error, res_b.error),
+            recovered: Vec::new(),
+            committed: res_b.committed,
+            incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
         }
     }
 }
@@ -84,6 +118,8 @@ impl<ParserA, ParserB> IntoParser for OrParser<ParserA, ParserB> {
 }
 
 #[cfg(test)]
+// exclusive ranges are intended to check the end is not included
+#[allow(clippy::almost_complete_range)]
 mod test {
     use super::*;
     use crate::leaf::singlerange::SingleRangeParser;
@@ -136,4 +172,40 @@ mod test {
         let rest: String = res.it.collect();
         assert_eq!(rest, "9a2b3c");
     }
+
+    #[test]
+    fn error_merged() {
+        let digit_parser = SingleRangeParser::from('0'..='9').label("digit");
+        let alpha_parser = SingleRangeParser::from('a'..='z').label("letter");
+
+        let digitalpha_parser = OrParser::new(digit_parser, alpha_parser);
+        let res = digitalpha_parser.parse("_a".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.error.unwrap().to_string(), "expected digit or letter");
+
+        // described alternatives are merged, undescribed ones add nothing
+        let parser = OrParser::new('x'.into_parser(), SingleRangeParser::from('0'..='9'));
+        let res = parser.parse("_".chars());
+        assert_eq!(res.error.unwrap().to_string(), "expected 'x'");
+    }
+
+    #[test]
+    fn error_furthest() {
+        use crate::wrapper::seq::SeqParser;
+
+        let digit_parser = SingleRangeParser::from('0'..='9').label("digit");
+        let alpha_parser = SingleRangeParser::from('a'..='z');
+
+        let parser = OrParser::new(
+            SeqParser::new(digit_parser.clone(), digit_parser.clone()),
+            SeqParser::new(digit_parser, alpha_parser),
+        );
+        let res = parser.parse("1a".chars());
+        assert_eq!(res.output, Some(('1', 'a')));
+
+        // first alternative failed at 'a', even though the whole parser succeeded
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "a");
+        assert_eq!(error.to_string(), "expected digit");
+    }
 }
@@ -29,30 +29,50 @@ where
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let res = self.parser.parse(it);
+        if res.output.is_none() && res.committed {
+            // hard error after cut; propagate instead of falling back
+            return ParseResult {
+                output: None,
+                it: res.it,
+                error: res.error,
+                recovered: Vec::new(),
+                committed: true,
+                incomplete: res.incomplete,
+            };
+        }
         if let Some(val) = res.output {
             return ParseResult {
                 output: Some(val),
                 it: res.it,
+                error: res.error,
+                recovered: res.recovered,
+                committed: false,
+                incomplete: res.incomplete,
             };
         }
         ParseResult {
             output: Some((self.closure)().wrap()),
             it: res.it,
+            error: res.error,
+            recovered: res.recovered,
+            committed: false,
+            incomplete: res.incomplete,
         }
     }
 
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
         let res = self.parser.match_pattern(it);
-        if res.output.is_some() {
-            ParseResult {
-                output: Some(()),
-                it: res.it,
-            }
-        } else {
-            ParseResult {
-                output: Some(()),
-                it: res.it,
-            }
+        if res.output.is_none() && res.committed {
+            // hard error after cut; propagate instead of falling back
+            return res;
+        }
+        ParseResult {
+            output: Some(()),
+            it: res.it,
+            error: res.error,
+            recovered: res.recovered,
+            committed: false,
+            incomplete: res.incomplete,
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
-use crate::core::parser::Parser;
+use crate::core::parser::{self, Parser};
 use crate::core::result::ParseResult;
 
 #[derive(Debug, Clone, Copy)]
@@ -30,18 +30,10 @@ where
     type Output = (OutputType,);
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
-        let res = self.parser.match_pattern(it);
-        if res.output.is_some() {
-            ParseResult {
-                output: Some((self.output.clone(),)),
-                it: res.it,
-            }
-        } else {
-            ParseResult {
-                output: None,
-                it: res.it,
-            }
-        }
+        parser::match_reporting(&self.parser, it).map_output(|()| (self.output.clone(),))
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.parser.match_pattern(it)
     }
 }
 
@@ -0,0 +1,450 @@
+use super::tupleutils::concat::AppendTupleToTuple;
+
+use crate::core::error::{self, ParseError};
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::partial::{self, Incomplete};
+use crate::core::result::ParseResult;
+use crate::core::tuple::Tuple;
+
+// members are a cons list `(a, (b, (c, ())))`;
+// each round takes the first unmatched member which consumes input.
+// members left unmatched must match without consuming, e.g. `optional()`,
+// so an optional member declared early does not shadow its occurrence later in the input.
+// whether a member consumed input is told by `error::advanced`, from the upper bound of `size_hint`;
+// for inputs without one, every match counts as consuming,
+// and the errors of the members are merged as if they failed at the same position.
+
+/// errors and flags accumulated over the members
+pub struct PermutationState<It> {
+    error: Option<ParseError<It>>,
+    recovered: Vec<ParseError<It>>,
+    committed: bool,
+    incomplete: Option<Incomplete>,
+}
+
+pub enum PermutationStep<It> {
+    /// a member consumed input, continue from here
+    Matched(It),
+    /// no unmatched member consumed input
+    Unmatched,
+    /// a member failed after cut
+    Failed,
+}
+
+pub trait PermutationMembers<It>
+where
+    It: InputIteratorTrait,
+{
+    type Output: Tuple;
+    /// outputs of the matched members
+    type Slots: Default;
+    /// flags of the matched members, for `match_pattern`
+    type Matched: Default;
+
+    fn step(
+        &self,
+        slots: &mut Self::Slots,
+        it: &It,
+        state: &mut PermutationState<It>,
+    ) -> PermutationStep<It>;
+    fn finish(
+        &self,
+        slots: Self::Slots,
+        it: It,
+        state: &mut PermutationState<It>,
+    ) -> Option<(Self::Output, It)>;
+
+    fn match_step(
+        &self,
+        matched: &mut Self::Matched,
+        it: &It,
+        state: &mut PermutationState<It>,
+    ) -> PermutationStep<It>;
+    fn match_finish(
+        &self,
+        matched: Self::Matched,
+        it: It,
+        state: &mut PermutationState<It>,
+    ) -> Option<It>;
+}
+
+impl<It> PermutationMembers<It> for ()
+where
+    It: InputIteratorTrait,
+{
+    type Output = ();
+    type Slots = ();
+    type Matched = ();
+
+    fn step(
+        &self,
+        _slots: &mut (),
+        _it: &It,
+        _state: &mut PermutationState<It>,
+    ) -> PermutationStep<It> {
+        PermutationStep::Unmatched
+    }
+    fn finish(
+        &self,
+        _slots: (),
+        it: It,
+        _state: &mut PermutationState<It>,
+    ) -> Option<(Self::Output, It)> {
+        Some(((), it))
+    }
+
+    fn match_step(
+        &self,
+        _matched: &mut (),
+        _it: &It,
+        _state: &mut PermutationState<It>,
+    ) -> PermutationStep<It> {
+        PermutationStep::Unmatched
+    }
+    fn match_finish(&self, _matched: (), it: It, _state: &mut PermutationState<It>) -> Option<It> {
+        Some(it)
+    }
+}
+
+impl<ParserType, Rest, It> PermutationMembers<It> for (ParserType, Rest)
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+    Rest: PermutationMembers<It>,
+    <ParserType as Parser<It>>::Output:
+        AppendTupleToTuple<<Rest as PermutationMembers<It>>::Output>,
+    <<ParserType as Parser<It>>::Output as AppendTupleToTuple<
+        <Rest as PermutationMembers<It>>::Output,
+    >>::Output: Tuple,
+{
+    type Output = <<ParserType as Parser<It>>::Output as AppendTupleToTuple<
+        <Rest as PermutationMembers<It>>::Output,
+    >>::Output;
+    type Slots = (
+        Option<<ParserType as Parser<It>>::Output>,
+        <Rest as PermutationMembers<It>>::Slots,
+    );
+    type Matched = (bool, <Rest as PermutationMembers<It>>::Matched);
+
+    fn step(
+        &self,
+        slots: &mut Self::Slots,
+        it: &It,
+        state: &mut PermutationState<It>,
+    ) -> PermutationStep<It> {
+        if slots.0.is_none() {
+            let res = self.0.parse(it.clone());
+            state.error = error::merge(state.error.take(), res.error);
+            state.incomplete = partial::merge(state.incomplete, res.incomplete);
+            match res.output {
+                Some(val) if error::advanced(it, &res.it) => {
+                    slots.0 = Some(val);
+                    state.recovered =
+                        error::concat(std::mem::take(&mut state.recovered), res.recovered);
+                    state.committed |= res.committed;
+                    return PermutationStep::Matched(res.it);
+                }
+                None if res.committed => {
+                    state.committed = true;
+                    return PermutationStep::Failed;
+                }
+                _ => {}
+            }
+        }
+        self.1.step(&mut slots.1, it, state)
+    }
+    fn finish(
+        &self,
+        slots: Self::Slots,
+        it: It,
+        state: &mut PermutationState<It>,
+    ) -> Option<(Self::Output, It)> {
+        let (val, it) = match slots.0 {
+            Some(val) => (val, it),
+            None => {
+                let res = self.0.parse(it);
+                state.error = error::merge(state.error.take(), res.error);
+                state.incomplete = partial::merge(state.incomplete, res.incomplete);
+                state.committed |= res.committed;
+                let val = res.output?;
+                state.recovered =
+                    error::concat(std::mem::take(&mut state.recovered), res.recovered);
+                (val, res.it)
+            }
+        };
+        let (rest, it) = self.1.finish(slots.1, it, state)?;
+        Some((val.append_back(rest), it))
+    }
+
+    fn match_step(
+        &self,
+        matched: &mut Self::Matched,
+        it: &It,
+        state: &mut PermutationState<It>,
+    ) -> PermutationStep<It> {
+        if !matched.0 {
+            let res = self.0.match_pattern(it.clone());
+            state.error = error::merge(state.error.take(), res.error);
+            state.incomplete = partial::merge(state.incomplete, res.incomplete);
+            match res.output {
+                Some(()) if error::advanced(it, &res.it) => {
+                    matched.0 = true;
+                    state.recovered =
+                        error::concat(std::mem::take(&mut state.recovered), res.recovered);
+                    state.committed |= res.committed;
+                    return PermutationStep::Matched(res.it);
+                }
+                None if res.committed => {
+                    state.committed = true;
+                    return PermutationStep::Failed;
+                }
+                _ => {}
+            }
+        }
+        self.1.match_step(&mut matched.1, it, state)
+    }
+    fn match_finish(
+        &self,
+        matched: Self::Matched,
+        it: It,
+        state: &mut PermutationState<It>,
+    ) -> Option<It> {
+        let it = if matched.0 {
+            it
+        } else {
+            let res = self.0.match_pattern(it);
+            state.error = error::merge(state.error.take(), res.error);
+            state.incomplete = partial::merge(state.incomplete, res.incomplete);
+            state.committed |= res.committed;
+            res.output?;
+            state.recovered = error::concat(std::mem::take(&mut state.recovered), res.recovered);
+            res.it
+        };
+        self.1.match_finish(matched.1, it, state)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PermutationParser<Members> {
+    members: Members,
+}
+
+impl<Members> PermutationParser<Members> {
+    pub fn new(members: Members) -> Self {
+        Self { members }
+    }
+}
+
+impl<Members, It> Parser<It> for PermutationParser<Members>
+where
+    It: InputIteratorTrait,
+    Members: PermutationMembers<It>,
+{
+    type Output = <Members as PermutationMembers<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let mut state = PermutationState {
+            error: None,
+            recovered: Vec::new(),
+            committed: false,
+            incomplete: None,
+        };
+        let mut slots = Default::default();
+        let mut it = it;
+        loop {
+            match self.members.step(&mut slots, &it, &mut state) {
+                PermutationStep::Matched(next) => it = next,
+                PermutationStep::Unmatched => break,
+                PermutationStep::Failed => {
+                    return ParseResult {
+                        output: None,
+                        it: i0,
+                        error: state.error,
+                        recovered: Vec::new(),
+                        committed: state.committed,
+                        incomplete: state.incomplete,
+                    }
+                }
+            }
+        }
+        match self.members.finish(slots, it, &mut state) {
+            Some((output, it)) => ParseResult {
+                output: Some(output),
+                it,
+                error: state.error,
+                recovered: state.recovered,
+                // cut only commits the rest of this permutation
+                committed: false,
+                incomplete: state.incomplete,
+            },
+            // some member is missing
+            None => ParseResult {
+                output: None,
+                it: i0,
+                error: state.error,
+                recovered: Vec::new(),
+                committed: state.committed,
+                incomplete: state.incomplete,
+            },
+        }
+    }
+
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let i0 = it.clone();
+        let mut state = PermutationState {
+            error: None,
+            recovered: Vec::new(),
+            committed: false,
+            incomplete: None,
+        };
+        let mut matched = Default::default();
+        let mut it = it;
+        loop {
+            match self.members.match_step(&mut matched, &it, &mut state) {
+                PermutationStep::Matched(next) => it = next,
+                PermutationStep::Unmatched => break,
+                PermutationStep::Failed => {
+                    return ParseResult {
+                        output: None,
+                        it: i0,
+                        error: state.error,
+                        recovered: Vec::new(),
+                        committed: state.committed,
+                        incomplete: state.incomplete,
+                    }
+                }
+            }
+        }
+        match self.members.match_finish(matched, it, &mut state) {
+            Some(it) => ParseResult {
+                output: Some(()),
+                it,
+                error: state.error,
+                recovered: state.recovered,
+                // cut only commits the rest of this permutation
+                committed: false,
+                incomplete: state.incomplete,
+            },
+            // some member is missing
+            None => ParseResult {
+                output: None,
+                it: i0,
+                error: state.error,
+                recovered: Vec::new(),
+                committed: state.committed,
+                incomplete: state.incomplete,
+            },
+        }
+    }
+}
+
+/// A permutation combinator over members nested as `(a, (b, (c, ())))`.
+/// Use [`crate::permutation!`] instead.
+pub fn permutation<Members>(members: Members) -> PermutationParser<Members> {
+    PermutationParser::new(members)
+}
+
+impl<Members> IntoParser for PermutationParser<Members> {
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::option::OptionalParser;
+    use crate::wrapper::seq::SeqParser;
+
+    #[test]
+    fn success1() {
+        let parser = permutation((
+            'a'.into_parser(),
+            (SingleRangeParser::from('0'..='9'), ('b'.into_parser(), ())),
+        ));
+
+        let res = parser.parse("b1a;".chars());
+        assert_eq!(res.output, Some(('a', '1', 'b')));
+        assert_eq!(res.it.as_str(), ";");
+
+        let res = parser.parse("ab1".chars());
+        assert_eq!(res.output, Some(('a', '1', 'b')));
+
+        // each member at most once
+        let res = parser.parse("aab1".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "aab1");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "ab1");
+
+        // missing member
+        let res = parser.parse("b1;".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.error.unwrap().to_string(), "expected 'a'");
+
+        let res = parser.match_pattern("1ba".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+    }
+
+    #[test]
+    fn optional() {
+        // optional member declared first still matches later in the input
+        let parser = permutation((
+            OptionalParser::new(SeqParser::new('-'.into_parser(), 'v'.into_parser())),
+            ('x'.into_parser(), ()),
+        ));
+
+        let res = parser.parse("x-v".chars());
+        assert_eq!(res.output, Some((Some(('-', 'v')), 'x')));
+
+        let res = parser.parse("x".chars());
+        assert_eq!(res.output, Some((None, 'x')));
+
+        let res = parser.match_pattern("x-v".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+    }
+
+    // input which cannot tell how many items are left
+    #[derive(Clone)]
+    struct Unsized<'a>(std::str::Chars<'a>);
+
+    impl Iterator for Unsized<'_> {
+        type Item = char;
+
+        fn next(&mut self) -> Option<char> {
+            self.0.next()
+        }
+    }
+
+    #[test]
+    fn unknown_position() {
+        let parser = permutation((
+            OptionalParser::new(SeqParser::new('-'.into_parser(), 'v'.into_parser())),
+            ('x'.into_parser(), ()),
+        ));
+
+        // an optional member matches where it is declared
+        let res = parser.parse(Unsized("x-v".chars()));
+        assert_eq!(res.output, Some((None, 'x')));
+        assert_eq!(res.it.0.as_str(), "-v");
+
+        let parser = permutation((
+            SeqParser::new('a'.into_parser(), 'b'.into_parser()),
+            ('c'.into_parser(), ()),
+        ));
+        let res = parser.parse("ax".chars());
+        assert_eq!(res.error.unwrap().to_string(), "expected 'b'");
+
+        // the furthest failure cannot be told apart
+        let res = parser.parse(Unsized("ax".chars()));
+        assert_eq!(res.output, None);
+        assert_eq!(res.error.unwrap().to_string(), "expected 'b' or 'c'");
+    }
+}
@@ -0,0 +1,620 @@
+//! Operator-precedence expression parser, using Pratt parsing.
+//!
+//! The operator table is built at compile-time as nested layers, one per operator;
+//! each layer tries the table below it first, so operators are tried in the order they are added.
+
+use crate::core::error;
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::{self, Parser};
+use crate::core::partial;
+use crate::core::result::ParseResult;
+
+/// Associativity of an infix operator of [`crate::pratt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Assoc {
+    /// `a - b - c` is `(a - b) - c`
+    Left,
+    /// `a ^ b ^ c` is `a ^ (b ^ c)`
+    Right,
+    /// `a == b == c` is not chained; the expression ends before the second operator
+    None,
+}
+
+// binding power; operand of an operator takes every operator binding at least this tight
+type Power = u64;
+
+// precedence `p` takes powers `2p` and `2p + 1`, so the associativity can break ties
+fn power(precedence: u32) -> Power {
+    Power::from(precedence) * 2
+}
+
+// object-safe `match_pattern` reporting errors, to borrow the closing parser of a group from the table
+pub trait MatchPattern<It>
+where
+    It: InputIteratorTrait,
+{
+    fn match_pattern(&self, it: It) -> ParseResult<(), It>;
+}
+
+impl<ParserType, It> MatchPattern<It> for ParserType
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        parser::match_reporting(self, it)
+    }
+}
+
+// operator which comes before an operand
+pub enum LeadingOperator<'p, It, T> {
+    Prefix {
+        power: Power,
+        fold: &'p dyn Fn(T) -> T,
+    },
+    Group {
+        close: &'p dyn MatchPattern<It>,
+    },
+}
+
+// operator which comes after an operand
+pub enum TrailingOperator<'p, T> {
+    Infix {
+        left: Power,
+        right: Power,
+        nonassoc: bool,
+        fold: &'p dyn Fn(T, T) -> T,
+    },
+    Postfix {
+        power: Power,
+        fold: &'p dyn Fn(T) -> T,
+    },
+}
+
+// Table of operators; `ParseResult` has the input after the matched operator,
+// and the furthest failure of the operators tried.
+pub trait OperatorTable<It, T>
+where
+    It: InputIteratorTrait,
+{
+    fn leading(&self, it: It) -> (ParseResult<(), It>, Option<LeadingOperator<'_, It, T>>);
+    fn trailing(&self, it: It) -> (ParseResult<(), It>, Option<TrailingOperator<'_, T>>);
+}
+
+// try the table below, then this layer's operator
+fn try_layer<It, Op, Operator>(
+    below: (ParseResult<(), It>, Option<Operator>),
+    op: &Op,
+    it: It,
+    operator: impl FnOnce() -> Operator,
+) -> (ParseResult<(), It>, Option<Operator>)
+where
+    It: InputIteratorTrait,
+    Op: Parser<It>,
+{
+    let (res, matched) = below;
+    if matched.is_some() || res.committed {
+        return (res, matched);
+    }
+    let own = parser::match_reporting(op, it);
+    let error = error::merge(res.error, own.error);
+    let incomplete = partial::merge(res.incomplete, own.incomplete);
+    let matched = own.output.map(|_| operator());
+    (
+        ParseResult {
+            error,
+            incomplete,
+            ..own
+        },
+        matched,
+    )
+}
+
+// empty table
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOperators;
+
+impl<It, T> OperatorTable<It, T> for NoOperators
+where
+    It: InputIteratorTrait,
+{
+    fn leading(&self, it: It) -> (ParseResult<(), It>, Option<LeadingOperator<'_, It, T>>) {
+        (
+            ParseResult {
+                output: None,
+                it,
+                error: None,
+                recovered: Vec::new(),
+                committed: false,
+                incomplete: None,
+            },
+            None,
+        )
+    }
+    fn trailing(&self, it: It) -> (ParseResult<(), It>, Option<TrailingOperator<'_, T>>) {
+        (
+            ParseResult {
+                output: None,
+                it,
+                error: None,
+                recovered: Vec::new(),
+                committed: false,
+                incomplete: None,
+            },
+            None,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PrefixLayer<Table, Op, Fold> {
+    table: Table,
+    op: Op,
+    power: Power,
+    fold: Fold,
+}
+
+impl<Table, Op, Fold, It, T> OperatorTable<It, T> for PrefixLayer<Table, Op, Fold>
+where
+    It: InputIteratorTrait,
+    Table: OperatorTable<It, T>,
+    Op: Parser<It>,
+    Fold: Fn(T) -> T,
+{
+    fn leading(&self, it: It) -> (ParseResult<(), It>, Option<LeadingOperator<'_, It, T>>) {
+        try_layer(self.table.leading(it.clone()), &self.op, it, || {
+            LeadingOperator::Prefix {
+                power: self.power,
+                fold: &self.fold,
+            }
+        })
+    }
+    fn trailing(&self, it: It) -> (ParseResult<(), It>, Option<TrailingOperator<'_, T>>) {
+        self.table.trailing(it)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GroupLayer<Table, Open, Close> {
+    table: Table,
+    open: Open,
+    close: Close,
+}
+
+impl<Table, Open, Close, It, T> OperatorTable<It, T> for GroupLayer<Table, Open, Close>
+where
+    It: InputIteratorTrait,
+    Table: OperatorTable<It, T>,
+    Open: Parser<It>,
+    Close: Parser<It>,
+{
+    fn leading(&self, it: It) -> (ParseResult<(), It>, Option<LeadingOperator<'_, It, T>>) {
+        try_layer(self.table.leading(it.clone()), &self.open, it, || {
+            LeadingOperator::Group { close: &self.close }
+        })
+    }
+    fn trailing(&self, it: It) -> (ParseResult<(), It>, Option<TrailingOperator<'_, T>>) {
+        self.table.trailing(it)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct InfixLayer<Table, Op, Fold> {
+    table: Table,
+    op: Op,
+    left: Power,
+    right: Power,
+    nonassoc: bool,
+    fold: Fold,
+}
+
+impl<Table, Op, Fold, It, T> OperatorTable<It, T> for InfixLayer<Table, Op, Fold>
+where
+    It: InputIteratorTrait,
+    Table: OperatorTable<It, T>,
+    Op: Parser<It>,
+    Fold: Fn(T, T) -> T,
+{
+    fn leading(&self, it: It) -> (ParseResult<(), It>, Option<LeadingOperator<'_, It, T>>) {
+        self.table.leading(it)
+    }
+    fn trailing(&self, it: It) -> (ParseResult<(), It>, Option<TrailingOperator<'_, T>>) {
+        try_layer(self.table.trailing(it.clone()), &self.op, it, || {
+            TrailingOperator::Infix {
+                left: self.left,
+                right: self.right,
+                nonassoc: self.nonassoc,
+                fold: &self.fold,
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PostfixLayer<Table, Op, Fold> {
+    table: Table,
+    op: Op,
+    power: Power,
+    fold: Fold,
+}
+
+impl<Table, Op, Fold, It, T> OperatorTable<It, T> for PostfixLayer<Table, Op, Fold>
+where
+    It: InputIteratorTrait,
+    Table: OperatorTable<It, T>,
+    Op: Parser<It>,
+    Fold: Fn(T) -> T,
+{
+    fn leading(&self, it: It) -> (ParseResult<(), It>, Option<LeadingOperator<'_, It, T>>) {
+        self.table.leading(it)
+    }
+    fn trailing(&self, it: It) -> (ParseResult<(), It>, Option<TrailingOperator<'_, T>>) {
+        try_layer(self.table.trailing(it.clone()), &self.op, it, || {
+            TrailingOperator::Postfix {
+                power: self.power,
+                fold: &self.fold,
+            }
+        })
+    }
+}
+
+// Expression of `atom`s and the operators in `table`;
+// output of the operators is discarded, and folded values are passed to the closures.
+#[derive(Debug, Clone, Copy)]
+pub struct PrattParser<Atom, Table> {
+    atom: Atom,
+    table: Table,
+}
+
+impl<Atom> PrattParser<Atom, NoOperators> {
+    pub fn new(atom: Atom) -> Self {
+        Self {
+            atom,
+            table: NoOperators,
+        }
+    }
+}
+
+impl<Atom, Table> PrattParser<Atom, Table> {
+    /// Add a prefix operator; higher `precedence` binds tighter.
+    ///
+    /// `fold` takes the output of the operand.
+    pub fn prefix<Op: IntoParser, Fold, T>(
+        self,
+        op: Op,
+        precedence: u32,
+        fold: Fold,
+    ) -> PrattParser<Atom, PrefixLayer<Table, Op::Into, Fold>>
+    where
+        Fold: Fn(T) -> T,
+    {
+        PrattParser {
+            atom: self.atom,
+            table: PrefixLayer {
+                table: self.table,
+                op: op.into_parser(),
+                // infix operators of the same precedence are not part of the operand
+                power: power(precedence) + 1,
+                fold,
+            },
+        }
+    }
+
+    /// Add an infix operator; higher `precedence` binds tighter.
+    ///
+    /// `fold` takes the output of the left and right operands.
+    pub fn infix<Op: IntoParser, Fold, T>(
+        self,
+        op: Op,
+        assoc: Assoc,
+        precedence: u32,
+        fold: Fold,
+    ) -> PrattParser<Atom, InfixLayer<Table, Op::Into, Fold>>
+    where
+        Fold: Fn(T, T) -> T,
+    {
+        let power = power(precedence);
+        let (left, right) = match assoc {
+            Assoc::Left | Assoc::None => (power, power + 1),
+            Assoc::Right => (power + 1, power),
+        };
+        PrattParser {
+            atom: self.atom,
+            table: InfixLayer {
+                table: self.table,
+                op: op.into_parser(),
+                left,
+                right,
+                nonassoc: assoc == Assoc::None,
+                fold,
+            },
+        }
+    }
+
+    /// Add a postfix operator; higher `precedence` binds tighter.
+    ///
+    /// `fold` takes the output of the operand.
+    pub fn postfix<Op: IntoParser, Fold, T>(
+        self,
+        op: Op,
+        precedence: u32,
+        fold: Fold,
+    ) -> PrattParser<Atom, PostfixLayer<Table, Op::Into, Fold>>
+    where
+        Fold: Fn(T) -> T,
+    {
+        PrattParser {
+            atom: self.atom,
+            table: PostfixLayer {
+                table: self.table,
+                op: op.into_parser(),
+                power: power(precedence),
+                fold,
+            },
+        }
+    }
+
+    /// Add a group, `open` expression `close`, which is parsed as an atom; e.g. parentheses.
+    pub fn group<Open: IntoParser, Close: IntoParser>(
+        self,
+        open: Open,
+        close: Close,
+    ) -> PrattParser<Atom, GroupLayer<Table, Open::Into, Close::Into>> {
+        PrattParser {
+            atom: self.atom,
+            table: GroupLayer {
+                table: self.table,
+                open: open.into_parser(),
+                close: close.into_parser(),
+            },
+        }
+    }
+}
+
+impl<Atom, Table> PrattParser<Atom, Table> {
+    // expression with every operator binding at least `min_power`
+    fn expression<It, T>(&self, it: It, min_power: Power) -> ParseResult<(T,), It>
+    where
+        It: InputIteratorTrait,
+        Atom: Parser<It, Output = (T,)>,
+        Table: OperatorTable<It, T>,
+    {
+        let i0 = it.clone();
+
+        // hard error after cut; propagate instead of trying other alternatives
+        macro_rules! fail {
+            ($error:expr, $incomplete:expr) => {
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error: $error,
+                    recovered: Vec::new(),
+                    committed: true,
+                    incomplete: $incomplete,
+                }
+            };
+        }
+
+        // operand: prefix operator, group or atom
+        let (res, leading) = self.table.leading(it.clone());
+        let mut error = res.error;
+        let mut incomplete = res.incomplete;
+        let mut operand = None;
+        match leading {
+            Some(LeadingOperator::Prefix { power, fold }) => {
+                let inner = self.expression(res.it, power);
+                error = error::merge(error, inner.error);
+                incomplete = partial::merge(incomplete, inner.incomplete);
+                match inner.output {
+                    Some((val,)) => {
+                        operand = Some((
+                            fold(val),
+                            inner.it,
+                            error::concat(res.recovered, inner.recovered),
+                        ))
+                    }
+                    None if inner.committed => fail!(error, incomplete),
+                    None => {}
+                }
+            }
+            Some(LeadingOperator::Group { close }) => {
+                let inner = self.expression(res.it, 0);
+                error = error::merge(error, inner.error);
+                incomplete = partial::merge(incomplete, inner.incomplete);
+                match inner.output {
+                    Some((val,)) => {
+                        let close_res = close.match_pattern(inner.it);
+                        error = error::merge(error, close_res.error);
+                        incomplete = partial::merge(incomplete, close_res.incomplete);
+                        if close_res.output.is_some() {
+                            let recovered = error::concat(res.recovered, inner.recovered);
+                            operand = Some((
+                                val,
+                                close_res.it,
+                                error::concat(recovered, close_res.recovered),
+                            ));
+                        } else if close_res.committed {
+                            fail!(error, incomplete);
+                        }
+                    }
+                    None if inner.committed => fail!(error, incomplete),
+                    None => {}
+                }
+            }
+            None if res.committed => fail!(error, incomplete),
+            None => {}
+        }
+        let (mut lhs, mut it, mut recovered) = match operand {
+            Some(operand) => operand,
+            None => {
+                let res = self.atom.parse(i0.clone());
+                error = error::merge(error, res.error);
+                incomplete = partial::merge(incomplete, res.incomplete);
+                match res.output {
+                    Some((val,)) => (val, res.it, res.recovered),
+                    None => {
+                        return ParseResult {
+                            output: None,
+                            it: i0,
+                            error,
+                            recovered: Vec::new(),
+                            committed: res.committed,
+                            incomplete,
+                        }
+                    }
+                }
+            }
+        };
+
+        // infix and postfix operators binding at least `min_power`
+        let mut last_nonassoc = None;
+        loop {
+            let (res, trailing) = self.table.trailing(it.clone());
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
+            match trailing {
+                Some(TrailingOperator::Postfix { power, fold }) if power >= min_power => {
+                    lhs = fold(lhs);
+                    it = res.it;
+                    recovered = error::concat(recovered, res.recovered);
+                    last_nonassoc = None;
+                }
+                Some(TrailingOperator::Infix {
+                    left,
+                    right,
+                    nonassoc,
+                    fold,
+                }) if left >= min_power && last_nonassoc != Some(left) => {
+                    let rhs = self.expression(res.it, right);
+                    error = error::merge(error, rhs.error);
+                    incomplete = partial::merge(incomplete, rhs.incomplete);
+                    match rhs.output {
+                        Some((val,)) => {
+                            lhs = fold(lhs, val);
+                            it = rhs.it;
+                            recovered = error::concat(recovered, res.recovered);
+                            recovered = error::concat(recovered, rhs.recovered);
+                            last_nonassoc = nonassoc.then_some(left);
+                        }
+                        None if rhs.committed => fail!(error, incomplete),
+                        None => break,
+                    }
+                }
+                Some(_) => break,
+                None if res.committed => fail!(error, incomplete),
+                None => break,
+            }
+        }
+
+        ParseResult {
+            output: Some((lhs,)),
+            it,
+            error,
+            recovered,
+            committed: false,
+            incomplete,
+        }
+    }
+}
+
+impl<Atom, Table, It, T> Parser<It> for PrattParser<Atom, Table>
+where
+    It: InputIteratorTrait,
+    Atom: Parser<It, Output = (T,)>,
+    Table: OperatorTable<It, T>,
+{
+    type Output = (T,);
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.expression(it, 0)
+    }
+}
+
+impl<Atom, Table> IntoParser for PrattParser<Atom, Table> {
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::map::MapParser;
+
+    fn calc() -> impl for<'a> Parser<std::str::Chars<'a>, Output = (i64,)> {
+        let digit = MapParser::new(
+            SingleRangeParser::from('0'..='9').label("digit"),
+            |ch: char| ch.to_digit(10).unwrap() as i64,
+        );
+        PrattParser::new(digit)
+            .group('('.into_parser(), ')'.into_parser())
+            .infix('+'.into_parser(), Assoc::Left, 1, |a: i64, b| a + b)
+            .infix('-'.into_parser(), Assoc::Left, 1, |a: i64, b| a - b)
+            .infix('*'.into_parser(), Assoc::Left, 2, |a: i64, b| a * b)
+            .infix('^'.into_parser(), Assoc::Right, 3, |a: i64, b| {
+                a.pow(b as u32)
+            })
+            .prefix('-'.into_parser(), 4, |a: i64| -a)
+            .postfix('!'.into_parser(), 5, |a: i64| (1..=a).product())
+    }
+
+    #[test]
+    fn precedence() {
+        let calc = calc();
+        let eval = |input: &str| calc.parse(input.chars()).output.map(|(val,)| val);
+
+        assert_eq!(eval("1+2*3"), Some(7));
+        assert_eq!(eval("(1+2)*3"), Some(9));
+        assert_eq!(eval("9-2-3"), Some(4));
+        assert_eq!(eval("2^3^2"), Some(512));
+        assert_eq!(eval("-2^2"), Some(4));
+        assert_eq!(eval("-3!"), Some(-6));
+        assert_eq!(eval("2*3!-1"), Some(11));
+        assert_eq!(eval("-(1-4)"), Some(3));
+        assert_eq!(eval("--1"), Some(1));
+    }
+
+    #[test]
+    fn rest() {
+        let calc = calc();
+
+        // dangling operator is left unconsumed
+        let res = calc.parse("1+2*".chars());
+        assert_eq!(res.output, Some((3,)));
+        assert_eq!(res.it.as_str(), "*");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "");
+        assert_eq!(error.to_string(), "expected '(', '-' or digit");
+
+        // unclosed group
+        let res = calc.parse("(1+2".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "(1+2");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "");
+        assert_eq!(error.to_string(), "expected '+', '-', '*', '^', '!' or ')'");
+    }
+
+    #[test]
+    fn nonassoc() {
+        let digit = SingleRangeParser::from('0'..='9');
+        let compare = PrattParser::new(MapParser::new(digit, |ch: char| ch.to_string()))
+            .infix('<'.into_parser(), Assoc::None, 1, |a: String, b| {
+                format!("({a}<{b})")
+            })
+            .infix('+'.into_parser(), Assoc::Left, 2, |a: String, b| {
+                format!("({a}+{b})")
+            });
+
+        let res = compare.parse("1<2+3".chars());
+        assert_eq!(res.output, Some(("(1<(2+3))".to_string(),)));
+
+        // not chained
+        let res = compare.parse("1<2<3".chars());
+        assert_eq!(res.output, Some(("(1<2)".to_string(),)));
+        assert_eq!(res.it.as_str(), "<3");
+    }
+}
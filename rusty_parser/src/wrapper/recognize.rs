@@ -0,0 +1,92 @@
+use super::tupleutils::concat::AppendTupleToTuple;
+
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+use crate::core::span::SliceInput;
+use crate::core::tuple::Tuple;
+
+// append the matched range, borrowed from the input, to the output of the parser
+#[derive(Debug, Clone, Copy)]
+pub struct RecognizeParser<ParserType> {
+    parser: ParserType,
+}
+
+impl<ParserType> RecognizeParser<ParserType> {
+    pub fn new(parser: ParserType) -> Self {
+        Self { parser }
+    }
+}
+
+impl<ParserType, It> Parser<It> for RecognizeParser<ParserType>
+where
+    It: InputIteratorTrait + SliceInput,
+    ParserType: Parser<It>,
+    <ParserType as Parser<It>>::Output: AppendTupleToTuple<(<It as SliceInput>::Slice,)>,
+    <<ParserType as Parser<It>>::Output as AppendTupleToTuple<(<It as SliceInput>::Slice,)>>::Output:
+        Tuple,
+{
+    type Output =
+        <<ParserType as Parser<It>>::Output as AppendTupleToTuple<(<It as SliceInput>::Slice,)>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let res = self.parser.parse(it);
+        if res.output.is_some() {
+            let slice = i0.consumed(&res.it);
+            res.map_output(|val| val.append_back((slice,)))
+        } else {
+            res.rewind(i0)
+        }
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<ParserType> IntoParser for RecognizeParser<ParserType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::repeat::RepeatParser;
+    use crate::wrapper::seq::SeqParser;
+
+    #[test]
+    fn slice() {
+        // length-prefixed field: one byte of length, then digits
+        let len = SingleRangeParser::from(&0..=&9);
+        let digits = RepeatParser::from(SingleRangeParser::from(&b'0'..=&b'9'), 1..);
+        let parser = RecognizeParser::new(SeqParser::new(len, digits));
+
+        let input = [3, b'1', b'2', b'3', b';'];
+        let res = parser.parse(input.iter());
+        let (len, digits, field) = res.output.unwrap();
+        assert_eq!(*len, 3);
+        assert_eq!(digits.len(), 3);
+        assert_eq!(field, &input[..4]);
+        assert_eq!(field.as_ptr(), input.as_ptr());
+        assert_eq!(res.it.as_slice(), b";");
+    }
+
+    #[test]
+    fn chars() {
+        let parser = RecognizeParser::new(SingleEqualParser::new('a'));
+
+        let res = parser.parse("abc".chars());
+        assert_eq!(res.output, Some(('a', "a")));
+
+        let res = parser.parse("bc".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "bc");
+    }
+}
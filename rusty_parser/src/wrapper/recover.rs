@@ -0,0 +1,365 @@
+use crate::core::error::{self, ParseError};
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::{self, Parser};
+use crate::core::partial;
+use crate::core::result::ParseResult;
+
+/// On failure of `parser`, record the error and parse `recovery` from the same position instead.
+///
+/// `recovery` matching without consuming any input counts as a failure,
+/// so a recovering parser inside `repeat` or `sep_by` always makes progress.
+#[derive(Debug, Clone, Copy)]
+pub struct RecoverParser<ParserType, RecoveryType> {
+    parser: ParserType,
+    recovery: RecoveryType,
+}
+
+impl<ParserType, RecoveryType> RecoverParser<ParserType, RecoveryType> {
+    pub fn new(parser: ParserType, recovery: RecoveryType) -> Self {
+        Self { parser, recovery }
+    }
+}
+
+impl<ParserType, RecoveryType, It> Parser<It> for RecoverParser<ParserType, RecoveryType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+    RecoveryType: Parser<It, Output = <ParserType as Parser<It>>::Output>,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let res = self.parser.parse(it);
+        if res.output.is_some() {
+            // the cut is scoped to `parser`, as an alternative of `recovery`
+            return ParseResult {
+                committed: false,
+                ..res
+            };
+        }
+        let res_recovery = self.recovery.parse(i0.clone());
+        if res_recovery.output.is_none() || !error::advanced(&i0, &res_recovery.it) {
+            return ParseResult {
+                output: None,
+                it: i0,
+                error: res.error,
+                recovered: Vec::new(),
+                committed: res.committed || res_recovery.committed,
+                incomplete: partial::merge(res.incomplete, res_recovery.incomplete),
+            };
+        }
+        let error = res.error.unwrap_or_else(|| ParseError::new(i0));
+        ParseResult {
+            output: res_recovery.output,
+            it: res_recovery.it,
+            error: res_recovery.error,
+            recovered: error::concat(vec![error], res_recovery.recovered),
+            committed: false,
+            incomplete: partial::merge(res.incomplete, res_recovery.incomplete),
+        }
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let i0 = it.clone();
+        let res = self.parser.match_pattern(it);
+        if res.output.is_some() {
+            return ParseResult {
+                committed: false,
+                ..res
+            };
+        }
+        let res_recovery = self.recovery.match_pattern(i0.clone());
+        if res_recovery.output.is_none() || !error::advanced(&i0, &res_recovery.it) {
+            return ParseResult {
+                output: None,
+                it: i0,
+                error: res.error,
+                recovered: Vec::new(),
+                committed: res.committed || res_recovery.committed,
+                incomplete: partial::merge(res.incomplete, res_recovery.incomplete),
+            };
+        }
+        let error = res.error.unwrap_or_else(|| ParseError::new(i0));
+        ParseResult {
+            output: res_recovery.output,
+            it: res_recovery.it,
+            error: res_recovery.error,
+            recovered: error::concat(vec![error], res_recovery.recovered),
+            committed: false,
+            incomplete: partial::merge(res.incomplete, res_recovery.incomplete),
+        }
+    }
+}
+
+impl<ParserType, RecoveryType> IntoParser for RecoverParser<ParserType, RecoveryType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+/// Skip items until `until` matches, consuming `until` as well.
+///
+/// Consumes at least one item; `until` matching nothing at the start does not count.
+#[derive(Debug, Clone, Copy)]
+pub struct SkipUntilParser<UntilType> {
+    until: UntilType,
+}
+
+impl<UntilType> SkipUntilParser<UntilType> {
+    pub fn new(until: UntilType) -> Self {
+        Self { until }
+    }
+}
+
+impl<UntilType> SkipUntilParser<UntilType> {
+    // `report`: build the error on failure, which `match_pattern` of `until` does not
+    fn skip<It>(&self, it: It, report: bool) -> ParseResult<(), It>
+    where
+        It: InputIteratorTrait,
+        UntilType: Parser<It>,
+    {
+        let i0 = it.clone();
+        let mut it = it;
+        let mut incomplete = None;
+        let mut skipped = false;
+        loop {
+            let res = self.until.match_pattern(it.clone());
+            incomplete = partial::merge(incomplete, res.incomplete);
+            if res.output.is_some() && (skipped || error::advanced(&i0, &res.it)) {
+                return ParseResult {
+                    output: Some(()),
+                    it: res.it,
+                    error: None,
+                    recovered: res.recovered,
+                    committed: false,
+                    incomplete,
+                };
+            }
+            skipped = true;
+            if it.next().is_none() {
+                // reached end of input without finding `until`
+                let error = if report {
+                    self.until.parse(it).error
+                } else {
+                    res.error
+                };
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    committed: false,
+                    incomplete,
+                };
+            }
+        }
+    }
+}
+
+impl<UntilType, It> Parser<It> for SkipUntilParser<UntilType>
+where
+    It: InputIteratorTrait,
+    UntilType: Parser<It>,
+{
+    type Output = ();
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.skip(it, true)
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.skip(it, false)
+    }
+}
+
+impl<UntilType> IntoParser for SkipUntilParser<UntilType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+/// Match `open`, then skip items until the matching `close`, respecting nested pairs.
+#[derive(Debug, Clone, Copy)]
+pub struct RecoverBetweenParser<OpenType, CloseType> {
+    open: OpenType,
+    close: CloseType,
+}
+
+impl<OpenType, CloseType> RecoverBetweenParser<OpenType, CloseType> {
+    pub fn new(open: OpenType, close: CloseType) -> Self {
+        Self { open, close }
+    }
+}
+
+impl<OpenType, CloseType> RecoverBetweenParser<OpenType, CloseType> {
+    // `report`: build the error on failure, which `match_pattern` of `open` and `close` does not
+    fn skip<It>(&self, it: It, report: bool) -> ParseResult<(), It>
+    where
+        It: InputIteratorTrait,
+        OpenType: Parser<It>,
+        CloseType: Parser<It>,
+    {
+        let i0 = it.clone();
+        let res = if report {
+            parser::match_reporting(&self.open, it)
+        } else {
+            self.open.match_pattern(it)
+        };
+        if res.output.is_none() {
+            return res.rewind(i0);
+        }
+        let mut it = res.it;
+        let mut depth: usize = 1;
+        let mut incomplete = res.incomplete;
+        loop {
+            let res_close = self.close.match_pattern(it.clone());
+            incomplete = partial::merge(incomplete, res_close.incomplete);
+            if res_close.output.is_some() {
+                it = res_close.it;
+                depth -= 1;
+                if depth == 0 {
+                    return ParseResult {
+                        incomplete,
+                        ..ParseResult::success((), it)
+                    };
+                }
+                continue;
+            }
+            let res_open = self.open.match_pattern(it.clone());
+            incomplete = partial::merge(incomplete, res_open.incomplete);
+            if res_open.output.is_some() {
+                it = res_open.it;
+                depth += 1;
+                continue;
+            }
+            if it.next().is_none() {
+                // reached end of input without closing all pairs
+                let error = if report {
+                    self.close.parse(it).error
+                } else {
+                    res_close.error
+                };
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    committed: false,
+                    incomplete,
+                };
+            }
+        }
+    }
+}
+
+impl<OpenType, CloseType, It> Parser<It> for RecoverBetweenParser<OpenType, CloseType>
+where
+    It: InputIteratorTrait,
+    OpenType: Parser<It>,
+    CloseType: Parser<It>,
+{
+    type Output = ();
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.skip(it, true)
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.skip(it, false)
+    }
+}
+
+impl<OpenType, CloseType> IntoParser for RecoverBetweenParser<OpenType, CloseType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::repeat::RepeatParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::wrapper::void::VoidParser;
+
+    #[test]
+    fn skip_until() {
+        let parser = SkipUntilParser::new(';'.into_parser());
+
+        let res = parser.parse("abc;def".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "def");
+
+        let res = parser.parse("abcdef".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "abcdef");
+        assert_eq!(res.error.unwrap().to_string(), "expected ';'");
+    }
+
+    #[test]
+    fn recover_between() {
+        let parser = RecoverBetweenParser::new('{'.into_parser(), '}'.into_parser());
+
+        let res = parser.parse("{a{b}c}d".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "d");
+
+        let res = parser.parse("{a{b}c".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "{a{b}c");
+
+        let res = parser.parse("a}".chars());
+        assert_eq!(res.output, None);
+    }
+
+    #[test]
+    fn recover_repeat() {
+        // stmt: digit ';'
+        let stmt = VoidParser::new(SeqParser::new(
+            SingleRangeParser::from('0'..='9').label("digit"),
+            ';'.into_parser(),
+        ));
+        let stmt = RecoverParser::new(stmt, SkipUntilParser::new(';'.into_parser()));
+        let stmts = RepeatParser::from(stmt, 0..);
+
+        let res = stmts.parse("1;x;2;3y;4;".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+
+        // every broken statement is reported
+        assert_eq!(res.recovered.len(), 2);
+        assert_eq!(res.recovered[0].it.as_str(), "x;2;3y;4;");
+        assert_eq!(res.recovered[0].to_string(), "expected digit");
+        assert_eq!(res.recovered[1].it.as_str(), "y;4;");
+        assert_eq!(res.recovered[1].to_string(), "expected ';'");
+    }
+
+    #[test]
+    fn recover_no_progress() {
+        let stmt = VoidParser::new(SeqParser::new(
+            SingleRangeParser::from('0'..='9'),
+            ';'.into_parser(),
+        ));
+        let spaces = VoidParser::new(RepeatParser::from(' '.into_parser(), 0..));
+
+        // recovery matching nothing fails, instead of repeating forever
+        let stmts = RepeatParser::from(RecoverParser::new(stmt, spaces), 0..);
+        let res = stmts.parse("1;x;".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "x;");
+        assert!(res.recovered.is_empty());
+
+        // `until` matching nothing at the start skips an item first
+        let stmts = RepeatParser::from(RecoverParser::new(stmt, SkipUntilParser::new(spaces)), 0..);
+        let res = stmts.parse("1;x".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+        assert_eq!(res.recovered.len(), 1);
+    }
+}
@@ -0,0 +1,320 @@
+use std::cell::OnceCell;
+use std::rc::{Rc, Weak};
+
+use crate::core::error::{Expected, ParseError};
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+use crate::core::tuple::Tuple;
+
+// each rule lives in a `OnceCell`, set right after the closure builds it;
+// references inside the rules are `Weak`, so the grammar does not leak through an `Rc` cycle.
+// the returned parsers hold all the rules of the set, so any of them can be dropped.
+
+/// Reference to a rule being defined by [`crate::recursive`].
+///
+/// It is meant to be used only as a part of the rules.
+/// Parsing with it outside of them, inside the closure or after the rules are dropped,
+/// fails without consuming the input.
+pub struct RecursiveRef<RuleType> {
+    rule: Weak<OnceCell<RuleType>>,
+}
+
+impl<RuleType> RecursiveRef<RuleType> {
+    // the rule reached from the parser returned from `recursive`; always defined and alive
+    fn with_rule<It, Output>(
+        &self,
+        it: It,
+        parse: impl FnOnce(&RuleType, It) -> ParseResult<Output, It>,
+    ) -> ParseResult<Output, It>
+    where
+        It: InputIteratorTrait,
+        Output: Tuple,
+    {
+        let Some(rule) = self.rule.upgrade() else {
+            return undefined(it);
+        };
+        match rule.get() {
+            Some(rule) => parse(rule, it),
+            None => undefined(it),
+        }
+    }
+}
+
+// failure of a reference used outside of its rules
+fn undefined<Output, It>(it: It) -> ParseResult<Output, It>
+where
+    Output: Tuple,
+    It: InputIteratorTrait,
+{
+    ParseResult::failure(
+        it.clone(),
+        ParseError::expected(
+            it,
+            Expected::Message("recursive rule used outside of its grammar".to_string()),
+        ),
+    )
+}
+
+impl<RuleType> Clone for RecursiveRef<RuleType> {
+    fn clone(&self) -> Self {
+        Self {
+            rule: Weak::clone(&self.rule),
+        }
+    }
+}
+
+impl<RuleType, It> Parser<It> for RecursiveRef<RuleType>
+where
+    It: InputIteratorTrait,
+    RuleType: Parser<It>,
+{
+    type Output = <RuleType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.with_rule(it, |rule, it| rule.parse(it))
+    }
+
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.with_rule(it, |rule, it| rule.match_pattern(it))
+    }
+}
+
+impl<RuleType> IntoParser for RecursiveRef<RuleType> {
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+/// Rule defined by [`crate::recursive`].
+///
+/// `Rules` holds all the rules of the same set, so they are alive as long as any of them is.
+pub struct RecursiveParser<RuleType, Rules> {
+    rule: Rc<OnceCell<RuleType>>,
+    _rules: Rc<Rules>,
+}
+
+impl<RuleType, Rules> Clone for RecursiveParser<RuleType, Rules> {
+    fn clone(&self) -> Self {
+        Self {
+            rule: Rc::clone(&self.rule),
+            _rules: Rc::clone(&self._rules),
+        }
+    }
+}
+
+impl<RuleType, Rules, It> Parser<It> for RecursiveParser<RuleType, Rules>
+where
+    It: InputIteratorTrait,
+    RuleType: Parser<It>,
+{
+    type Output = <RuleType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.rule
+            .get()
+            .expect("recursive rule is always defined")
+            .parse(it)
+    }
+
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.rule
+            .get()
+            .expect("recursive rule is always defined")
+            .match_pattern(it)
+    }
+}
+
+impl<RuleType, Rules> IntoParser for RecursiveParser<RuleType, Rules> {
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+// a rule of the set, shared by the returned parsers
+type Rule<RuleType> = Rc<OnceCell<RuleType>>;
+
+fn declare<RuleType>() -> (Rule<RuleType>, RecursiveRef<RuleType>) {
+    let rule = Rc::new(OnceCell::new());
+    let reference = RecursiveRef {
+        rule: Rc::downgrade(&rule),
+    };
+    (rule, reference)
+}
+
+fn define<RuleType, Rules>(
+    rule: &Rule<RuleType>,
+    parser: RuleType,
+    rules: &Rc<Rules>,
+) -> RecursiveParser<RuleType, Rules> {
+    if rule.set(parser).is_err() {
+        unreachable!("recursive rule is defined twice");
+    }
+    RecursiveParser {
+        rule: Rc::clone(rule),
+        _rules: Rc::clone(rules),
+    }
+}
+
+pub fn recursive<RuleType, ClosureType>(definition: ClosureType) -> RecursiveParser<RuleType, ()>
+where
+    ClosureType: FnOnce(RecursiveRef<RuleType>) -> RuleType,
+{
+    let (rule, reference) = declare();
+    let parser = definition(reference);
+    define(&rule, parser, &Rc::new(()))
+}
+
+#[allow(clippy::type_complexity)]
+pub fn recursive2<RuleA, RuleB, ClosureType>(
+    definition: ClosureType,
+) -> (
+    RecursiveParser<RuleA, (Rule<RuleA>, Rule<RuleB>)>,
+    RecursiveParser<RuleB, (Rule<RuleA>, Rule<RuleB>)>,
+)
+where
+    ClosureType: FnOnce(RecursiveRef<RuleA>, RecursiveRef<RuleB>) -> (RuleA, RuleB),
+{
+    let (rule_a, reference_a) = declare();
+    let (rule_b, reference_b) = declare();
+    let (parser_a, parser_b) = definition(reference_a, reference_b);
+    let rules = Rc::new((Rc::clone(&rule_a), Rc::clone(&rule_b)));
+    (
+        define(&rule_a, parser_a, &rules),
+        define(&rule_b, parser_b, &rules),
+    )
+}
+
+#[allow(clippy::type_complexity)]
+pub fn recursive3<RuleA, RuleB, RuleC, ClosureType>(
+    definition: ClosureType,
+) -> (
+    RecursiveParser<RuleA, (Rule<RuleA>, Rule<RuleB>, Rule<RuleC>)>,
+    RecursiveParser<RuleB, (Rule<RuleA>, Rule<RuleB>, Rule<RuleC>)>,
+    RecursiveParser<RuleC, (Rule<RuleA>, Rule<RuleB>, Rule<RuleC>)>,
+)
+where
+    ClosureType: FnOnce(
+        RecursiveRef<RuleA>,
+        RecursiveRef<RuleB>,
+        RecursiveRef<RuleC>,
+    ) -> (RuleA, RuleB, RuleC),
+{
+    let (rule_a, reference_a) = declare();
+    let (rule_b, reference_b) = declare();
+    let (rule_c, reference_c) = declare();
+    let (parser_a, parser_b, parser_c) = definition(reference_a, reference_b, reference_c);
+    let rules = Rc::new((Rc::clone(&rule_a), Rc::clone(&rule_b), Rc::clone(&rule_c)));
+    (
+        define(&rule_a, parser_a, &rules),
+        define(&rule_b, parser_b, &rules),
+        define(&rule_c, parser_c, &rules),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::check::SingleCheckParser;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::wrapper::boxed::chars::DynBoxChars;
+    use crate::wrapper::option::OptionalParser;
+    use crate::wrapper::or::OrParser;
+    use crate::wrapper::output::OutputParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::wrapper::void::VoidParser;
+
+    #[test]
+    fn success1() {
+        // paren: '(' paren? ')'
+        let paren = recursive(|paren| {
+            DynBoxChars::new(VoidParser::new(SeqParser::new(
+                SeqParser::new(SingleEqualParser::new('('), OptionalParser::new(paren)),
+                SingleEqualParser::new(')'),
+            )))
+        });
+
+        let res = paren.parse("(())".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+
+        let res = paren.match_pattern("(()".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "(()");
+    }
+
+    #[test]
+    fn mutual() {
+        // a: 'a' b | '.'
+        // b: 'b' a
+        let (a, b) = recursive2(|a, b| {
+            (
+                DynBoxChars::new(OrParser::new(
+                    VoidParser::new(SeqParser::new(SingleEqualParser::new('a'), b)),
+                    VoidParser::new(SingleEqualParser::new('.')),
+                )),
+                DynBoxChars::new(VoidParser::new(SeqParser::new(
+                    SingleEqualParser::new('b'),
+                    a,
+                ))),
+            )
+        });
+
+        // the other rule is kept alive
+        drop(a);
+        let res = b.parse("bab.".chars());
+        assert_eq!(res.output, Some(()));
+        let res = b.parse("bb.".chars());
+        assert_eq!(res.output, None);
+    }
+
+    #[test]
+    fn used_outside() {
+        let mut escaped = None;
+        let one = recursive(|one: RecursiveRef<DynBoxChars<(i32,)>>| {
+            // not defined yet
+            let res = one.parse("1".chars());
+            assert_eq!(res.output, None);
+            assert_eq!(res.it.as_str(), "1");
+            escaped = Some(one);
+            DynBoxChars::new(OutputParser::new(SingleEqualParser::new('1'), 1))
+        });
+        let escaped = escaped.unwrap();
+        assert_eq!(escaped.parse("1".chars()).output, Some((1,)));
+
+        // the rules are dropped
+        drop(one);
+        let res = escaped.parse("1".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(
+            res.error.unwrap().to_string(),
+            "recursive rule used outside of its grammar"
+        );
+    }
+
+    #[test]
+    fn non_static() {
+        // a rule outside of the cycle may borrow from the enclosing scope
+        let sigils = String::from("$@");
+        let (_paren, sigil_paren) = recursive2(|paren, _sigil_paren| {
+            (
+                DynBoxChars::new(VoidParser::new(SeqParser::new(
+                    SeqParser::new(
+                        SingleEqualParser::new('('),
+                        OptionalParser::new(paren.clone()),
+                    ),
+                    SingleEqualParser::new(')'),
+                ))),
+                SeqParser::new(
+                    VoidParser::new(SingleCheckParser::new(|ch: char| sigils.contains(ch))),
+                    paren,
+                ),
+            )
+        });
+        assert_eq!(sigil_paren.parse("@(())".chars()).output, Some(()));
+        assert_eq!(sigil_paren.parse("#()".chars()).output, None);
+    }
+}
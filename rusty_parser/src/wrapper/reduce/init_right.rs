@@ -1,6 +1,8 @@
+use crate::core::error;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
 use crate::core::tuple::Tuple;
 
@@ -40,13 +42,30 @@ where
     type Output = (Init,);
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
         let mut lhs_outputs: Vec<LhsOutput> = Vec::new();
         let mut it = it;
+        let mut error = None;
+        let mut incomplete = None;
+        let mut recovered = Vec::new();
         loop {
             let res = self.lhs.parse(it);
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
+            recovered = error::concat(recovered, res.recovered);
             it = res.it;
             if let Some(output) = res.output {
                 lhs_outputs.push(output);
+            } else if res.committed {
+                // hard error after cut; propagate instead of stopping the repetition
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    committed: true,
+                    incomplete,
+                };
             } else {
                 break;
             }
@@ -61,16 +80,42 @@ where
         ParseResult {
             output: Some((init,)),
             it,
+            error,
+            recovered,
+            committed: false,
+            incomplete,
         }
     }
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let i0 = it.clone();
         let mut it = it;
+        let mut error = None;
+        let mut incomplete = None;
+        let mut recovered = Vec::new();
         loop {
             let res = self.lhs.match_pattern(it);
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
+            recovered = error::concat(recovered, res.recovered);
             if res.output.is_none() {
+                if res.committed {
+                    // hard error after cut; propagate instead of stopping the repetition
+                    return ParseResult {
+                        output: None,
+                        it: i0,
+                        error,
+                        recovered: Vec::new(),
+                        committed: true,
+                        incomplete,
+                    };
+                }
                 return ParseResult {
                     output: Some(()),
                     it: res.it,
+                    error,
+                    recovered,
+                    committed: false,
+                    incomplete,
                 };
             }
             it = res.it;
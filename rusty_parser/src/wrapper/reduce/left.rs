@@ -1,6 +1,8 @@
+use crate::core::error;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
 use crate::core::tuple::Tuple;
 
@@ -35,19 +37,35 @@ where
     type Output = LhsOutput;
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
         let mut lhs_res = self.lhs.parse(it);
         if lhs_res.output.is_none() {
-            return ParseResult {
-                output: None,
-                it: lhs_res.it,
-            };
+            return lhs_res;
         }
         loop {
             let rhs_res = self.rhs.parse(lhs_res.it);
+            lhs_res.error = error::merge(lhs_res.error, rhs_res.error);
+            lhs_res.incomplete = partial::merge(lhs_res.incomplete, rhs_res.incomplete);
+            lhs_res.recovered = error::concat(lhs_res.recovered, rhs_res.recovered);
             if rhs_res.output.is_none() {
+                if rhs_res.committed {
+                    // hard error after cut; propagate instead of stopping the repetition
+                    return ParseResult {
+                        output: None,
+                        it: i0,
+                        error: lhs_res.error,
+                        recovered: Vec::new(),
+                        committed: true,
+                        incomplete: lhs_res.incomplete,
+                    };
+                }
                 return ParseResult {
                     output: lhs_res.output,
                     it: rhs_res.it,
+                    error: lhs_res.error,
+                    recovered: lhs_res.recovered,
+                    committed: false,
+                    incomplete: lhs_res.incomplete,
                 };
             }
 
@@ -60,19 +78,35 @@ where
         }
     }
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let i0 = it.clone();
         let mut lhs_res = self.lhs.match_pattern(it);
         if lhs_res.output.is_none() {
-            return ParseResult {
-                output: None,
-                it: lhs_res.it,
-            };
+            return lhs_res;
         }
         loop {
             let rhs_res = self.rhs.match_pattern(lhs_res.it);
+            lhs_res.error = error::merge(lhs_res.error, rhs_res.error);
+            lhs_res.incomplete = partial::merge(lhs_res.incomplete, rhs_res.incomplete);
+            lhs_res.recovered = error::concat(lhs_res.recovered, rhs_res.recovered);
             if rhs_res.output.is_none() {
+                if rhs_res.committed {
+                    // hard error after cut; propagate instead of stopping the repetition
+                    return ParseResult {
+                        output: None,
+                        it: i0,
+                        error: lhs_res.error,
+                        recovered: Vec::new(),
+                        committed: true,
+                        incomplete: lhs_res.incomplete,
+                    };
+                }
                 return ParseResult {
                     output: Some(()),
                     it: rhs_res.it,
+                    error: lhs_res.error,
+                    recovered: lhs_res.recovered,
+                    committed: false,
+                    incomplete: lhs_res.incomplete,
                 };
             }
             lhs_res.it = rhs_res.it;
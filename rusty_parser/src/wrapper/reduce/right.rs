@@ -1,6 +1,8 @@
+use crate::core::error;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
 use crate::core::tuple::Tuple;
 
@@ -39,22 +41,40 @@ where
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let i0 = it.clone();
 
-        // checkpoints after success of lhs
-        let mut lhs_its: Vec<(LhsOutput, It)> = Vec::new();
+        // checkpoints after success of lhs, with the number of errors recovered until there
+        let mut lhs_its: Vec<(LhsOutput, It, usize)> = Vec::new();
         let mut it = it;
+        let mut error = None;
+        let mut incomplete = None;
+        let mut recovered = Vec::new();
         loop {
             let res_lhs = self.lhs.parse(it);
+            error = error::merge(error, res_lhs.error);
+            incomplete = partial::merge(incomplete, res_lhs.incomplete);
+            recovered = error::concat(recovered, res_lhs.recovered);
             if let Some(res) = res_lhs.output {
-                lhs_its.push((res, res_lhs.it.clone()));
+                lhs_its.push((res, res_lhs.it.clone(), recovered.len()));
                 it = res_lhs.it;
+            } else if res_lhs.committed {
+                // hard error after cut; propagate instead of backtracking
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    committed: true,
+                    incomplete,
+                };
             } else {
                 break;
             }
         }
 
         // now unloop lhs_its backward and check if rhs matches.
-        while let Some((lhs_output, it)) = lhs_its.pop() {
+        while let Some((lhs_output, it, recovered_len)) = lhs_its.pop() {
             let res_rhs = self.rhs.parse(it);
+            error = error::merge(error, res_rhs.error);
+            incomplete = partial::merge(incomplete, res_rhs.incomplete);
             if let Some(mut rhs_output) = res_rhs.output {
                 // rhs matches; reduce and return
 
@@ -62,13 +82,29 @@ where
                 let output_merged = lhs_output.append_back(rhs_output);
                 rhs_output = self.reducer.map(output_merged).wrap();
 
-                while let Some((lhs_output, _)) = lhs_its.pop() {
+                while let Some((lhs_output, _, _)) = lhs_its.pop() {
                     let output_merged = lhs_output.append_back(rhs_output);
                     rhs_output = self.reducer.map(output_merged).wrap();
                 }
+                // discard errors recovered by lhs after this checkpoint
+                recovered.truncate(recovered_len);
                 return ParseResult {
                     output: Some(rhs_output),
                     it: res_rhs.it,
+                    error,
+                    recovered: error::concat(recovered, res_rhs.recovered),
+                    committed: false,
+                    incomplete,
+                };
+            } else if res_rhs.committed {
+                // hard error after cut; propagate instead of backtracking
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    committed: true,
+                    incomplete,
                 };
             } else {
                 continue;
@@ -77,32 +113,73 @@ where
 
         // rhs matches failed for all lhs_its
         // try i0
-        self.rhs.parse(i0)
+        let res_rhs = self.rhs.parse(i0);
+        ParseResult {
+            output: res_rhs.output,
+            it: res_rhs.it,
+            error: error::merge(error, res_rhs.error),
+            recovered: res_rhs.recovered,
+            committed: res_rhs.committed,
+            incomplete: partial::merge(incomplete, res_rhs.incomplete),
+        }
     }
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
         let i0 = it.clone();
 
-        // checkpoints after success of lhs
-        let mut lhs_its: Vec<It> = Vec::new();
+        // checkpoints after success of lhs, with the number of errors recovered until there
+        let mut lhs_its: Vec<(It, usize)> = Vec::new();
         let mut it = it;
+        let mut error = None;
+        let mut incomplete = None;
+        let mut recovered = Vec::new();
         loop {
             let res_lhs = self.lhs.match_pattern(it);
+            error = error::merge(error, res_lhs.error);
+            incomplete = partial::merge(incomplete, res_lhs.incomplete);
+            recovered = error::concat(recovered, res_lhs.recovered);
             if res_lhs.output.is_some() {
-                lhs_its.push(res_lhs.it.clone());
+                lhs_its.push((res_lhs.it.clone(), recovered.len()));
                 it = res_lhs.it;
+            } else if res_lhs.committed {
+                // hard error after cut; propagate instead of backtracking
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    committed: true,
+                    incomplete,
+                };
             } else {
                 break;
             }
         }
 
         // now unloop lhs_its backward and check if rhs matches.
-        while let Some(it) = lhs_its.pop() {
+        while let Some((it, recovered_len)) = lhs_its.pop() {
             let res_rhs = self.rhs.match_pattern(it);
+            error = error::merge(error, res_rhs.error);
+            incomplete = partial::merge(incomplete, res_rhs.incomplete);
             if res_rhs.output.is_some() {
                 // rhs matches; return
+                recovered.truncate(recovered_len);
                 return ParseResult {
                     output: Some(()),
                     it: res_rhs.it,
+                    error,
+                    recovered: error::concat(recovered, res_rhs.recovered),
+                    committed: false,
+                    incomplete,
+                };
+            } else if res_rhs.committed {
+                // hard error after cut; propagate instead of backtracking
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    committed: true,
+                    incomplete,
                 };
             } else {
                 continue;
@@ -111,7 +188,15 @@ where
 
         // rhs matches failed for all lhs_its
         // try i0
-        self.rhs.match_pattern(i0)
+        let res_rhs = self.rhs.match_pattern(i0);
+        ParseResult {
+            output: res_rhs.output,
+            it: res_rhs.it,
+            error: error::merge(error, res_rhs.error),
+            recovered: res_rhs.recovered,
+            committed: res_rhs.committed,
+            incomplete: partial::merge(incomplete, res_rhs.incomplete),
+        }
     }
 }
 
@@ -1,6 +1,8 @@
+use crate::core::error;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::range_copyable::{RangeBound, ToCopyable};
 use crate::core::result::ParseResult;
 use crate::core::tuple::Tuple;
@@ -52,28 +54,48 @@ where
             <<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::new_output();
         let mut it = it;
         let mut count: RepeatCountType = 0;
+        // furthest failure among the repetitions
+        let mut error = None;
+        let mut incomplete = None;
+        let mut recovered = Vec::new();
         loop {
             // check reached max count
             if self.range.contains(&count) && !self.range.contains(&(count + 1)) {
                 return ParseResult {
                     output: Some(output),
                     it,
+                    error,
+                    recovered,
+                    committed: false,
+                    incomplete,
                 };
             }
             let res = self.parser.parse(it);
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
             if let Some(val) = res.output {
                 count += 1;
+                recovered = error::concat(recovered, res.recovered);
                 val.push_this_to_output(&mut output);
                 it = res.it;
-            } else if self.range.contains(&count) {
+            } else if self.range.contains(&count) && !res.committed {
                 return ParseResult {
                     output: Some(output),
                     it: res.it,
+                    error,
+                    recovered,
+                    committed: false,
+                    incomplete,
                 };
             } else {
                 return ParseResult {
                     output: None,
                     it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    // not enough repetitions, or hard error after cut
+                    committed: res.committed,
+                    incomplete,
                 };
             }
         }
@@ -82,27 +104,47 @@ where
         let i0 = it.clone();
         let mut it = it;
         let mut count: RepeatCountType = 0;
+        // furthest failure among the repetitions
+        let mut error = None;
+        let mut incomplete = None;
+        let mut recovered = Vec::new();
         loop {
             // check reached max count
             if self.range.contains(&count) && !self.range.contains(&(count + 1)) {
                 return ParseResult {
                     output: Some(()),
                     it,
+                    error,
+                    recovered,
+                    committed: false,
+                    incomplete,
                 };
             }
             let res = self.parser.match_pattern(it);
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
             if res.output.is_some() {
                 count += 1;
+                recovered = error::concat(recovered, res.recovered);
                 it = res.it;
-            } else if self.range.contains(&count) {
+            } else if self.range.contains(&count) && !res.committed {
                 return ParseResult {
                     output: Some(()),
                     it: res.it,
+                    error,
+                    recovered,
+                    committed: false,
+                    incomplete,
                 };
             } else {
                 return ParseResult {
                     output: None,
                     it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    // not enough repetitions, or hard error after cut
+                    committed: res.committed,
+                    incomplete,
                 };
             }
         }
@@ -191,7 +233,7 @@ mod test {
     }
     #[test]
     fn fail1() {
-        let digit_parser = SingleRangeParser::from('0'..='9');
+        let digit_parser = SingleRangeParser::from('0'..='9').label("digit");
         let repeat_parser = RepeatParser::from(digit_parser, 5..10);
 
         let str = "1234abcd";
@@ -200,5 +242,27 @@ mod test {
         assert_eq!(res.output, None);
         let rest: String = res.it.collect();
         assert_eq!(rest, "1234abcd");
+        // error points to the fifth item that failed
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "abcd");
+        assert_eq!(error.to_string(), "expected digit");
+    }
+
+    #[test]
+    fn partial() {
+        let digits = RepeatParser::from(SingleRangeParser::from('0'..='9'), 1..);
+
+        // more digits may follow
+        let res = crate::parse_partial(&digits, "123".chars());
+        assert_eq!(res.unwrap_err(), crate::Incomplete { needed: 1 });
+
+        let res = crate::parse_partial(&digits, "123;".chars()).unwrap();
+        assert_eq!(res.output, Some((vec!['1', '2', '3'],)));
+        assert!(!res.committed);
+
+        // the same parse, read as complete input
+        let res = digits.parse("123".chars());
+        assert_eq!(res.output, Some((vec!['1', '2', '3'],)));
+        assert_eq!(res.incomplete, Some(crate::Incomplete { needed: 1 }));
     }
 }
@@ -0,0 +1,280 @@
+use super::tupleutils::concat::AppendTupleToTuple;
+
+use crate::core::error;
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::partial;
+use crate::core::range_copyable::{RangeBound, ToCopyable};
+use crate::core::result::ParseResult;
+use crate::core::tuple::Tuple;
+use crate::wrapper::repeat::RepeatCountType;
+use crate::wrapper::tupleutils::vec::VectorOutputSpecialize;
+
+// repeat the parser until the terminator matches;
+// once the minimum count is reached the terminator is tried first at each step,
+// so it is evaluated once per item; below the minimum only the parser is tried
+#[derive(Debug, Clone, Copy)]
+pub struct RepeatUntilParser<ParserType, EndType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+{
+    parser: ParserType,
+    end: EndType,
+    range: RangeType,
+}
+
+impl<ParserType, EndType, RangeType> RepeatUntilParser<ParserType, EndType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+{
+    pub fn new(parser: ParserType, end: EndType, range: RangeType) -> Self {
+        Self { parser, end, range }
+    }
+    pub fn from<RangeType_>(parser: ParserType, end: EndType, range: RangeType_) -> Self
+    where
+        RangeType_: ToCopyable<Into = RangeType>,
+    {
+        Self {
+            parser,
+            end,
+            range: range.into(),
+        }
+    }
+}
+
+impl<ParserType, EndType, RangeType, It> Parser<It>
+    for RepeatUntilParser<ParserType, EndType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+    EndType: Parser<It>,
+    <ParserType as Parser<It>>::Output: VectorOutputSpecialize,
+    <<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::Output:
+        AppendTupleToTuple<<EndType as Parser<It>>::Output>,
+    <<<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::Output as AppendTupleToTuple<
+        <EndType as Parser<It>>::Output,
+    >>::Output: Tuple,
+{
+    type Output =
+        <<<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::Output as AppendTupleToTuple<
+            <EndType as Parser<It>>::Output,
+        >>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let mut output =
+            <<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::new_output();
+        let mut it = it;
+        let mut count: RepeatCountType = 0;
+        // furthest failure among the repetitions
+        let mut error = None;
+        let mut incomplete = None;
+        let mut recovered = Vec::new();
+        loop {
+            if self.range.contains(&count) {
+                let end_res = self.end.parse(it.clone());
+                error = error::merge(error, end_res.error);
+                incomplete = partial::merge(incomplete, end_res.incomplete);
+                if let Some(end_val) = end_res.output {
+                    return ParseResult {
+                        output: Some(output.append_back(end_val)),
+                        it: end_res.it,
+                        error,
+                        recovered: error::concat(recovered, end_res.recovered),
+                        committed: false,
+                        incomplete,
+                    };
+                }
+                // the terminator is required after the maximum count
+                if end_res.committed || !self.range.contains(&(count + 1)) {
+                    return ParseResult {
+                        output: None,
+                        it: i0,
+                        error,
+                        recovered: Vec::new(),
+                        committed: end_res.committed,
+                        incomplete,
+                    };
+                }
+            }
+
+            // below the minimum count, or the terminator did not match
+            let res = self.parser.parse(it);
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
+            if let Some(val) = res.output {
+                count += 1;
+                recovered = error::concat(recovered, res.recovered);
+                val.push_this_to_output(&mut output);
+                it = res.it;
+            } else {
+                // neither the parser nor the terminator matched
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    committed: res.committed,
+                    incomplete,
+                };
+            }
+        }
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let i0 = it.clone();
+        let mut it = it;
+        let mut count: RepeatCountType = 0;
+        // furthest failure among the repetitions
+        let mut error = None;
+        let mut incomplete = None;
+        let mut recovered = Vec::new();
+        loop {
+            if self.range.contains(&count) {
+                let end_res = self.end.match_pattern(it.clone());
+                error = error::merge(error, end_res.error);
+                incomplete = partial::merge(incomplete, end_res.incomplete);
+                if end_res.output.is_some() {
+                    return ParseResult {
+                        output: Some(()),
+                        it: end_res.it,
+                        error,
+                        recovered: error::concat(recovered, end_res.recovered),
+                        committed: false,
+                        incomplete,
+                    };
+                }
+                // the terminator is required after the maximum count
+                if end_res.committed || !self.range.contains(&(count + 1)) {
+                    return ParseResult {
+                        output: None,
+                        it: i0,
+                        error,
+                        recovered: Vec::new(),
+                        committed: end_res.committed,
+                        incomplete,
+                    };
+                }
+            }
+
+            // below the minimum count, or the terminator did not match
+            let res = self.parser.match_pattern(it);
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
+            if res.output.is_some() {
+                count += 1;
+                recovered = error::concat(recovered, res.recovered);
+                it = res.it;
+            } else {
+                // neither the parser nor the terminator matched
+                return ParseResult {
+                    output: None,
+                    it: i0,
+                    error,
+                    recovered: Vec::new(),
+                    committed: res.committed,
+                    incomplete,
+                };
+            }
+        }
+    }
+}
+
+impl<ParserType, EndType, RangeType> IntoParser
+    for RepeatUntilParser<ParserType, EndType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::any::AnyParser;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::leaf::stringeq::StrEqualParser;
+
+    #[test]
+    fn success1() {
+        let comment = RepeatUntilParser::from(AnyParser::new(), StrEqualParser::new("*/"), 0..);
+
+        let res = comment.parse("a * b */ c".chars());
+        assert_eq!(res.output, Some((vec!['a', ' ', '*', ' ', 'b', ' '],)));
+        assert_eq!(res.it.as_str(), " c");
+
+        let res = comment.parse("*/".chars());
+        assert_eq!(res.output, Some((vec![],)));
+
+        // unterminated
+        let res = comment.parse("a * b".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "a * b");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "");
+        assert_eq!(error.to_string(), "expected \"*/\" or any item");
+
+        let res = comment.match_pattern("a */".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+    }
+
+    #[test]
+    fn terminator_output() {
+        let digits = RepeatUntilParser::from(
+            SingleRangeParser::from('0'..='9').label("digit"),
+            SingleRangeParser::from('a'..='z').label("letter"),
+            1..=3,
+        );
+
+        let res = digits.parse("12x".chars());
+        assert_eq!(res.output, Some((vec!['1', '2'], 'x')));
+
+        // fewer repetitions than the minimum
+        let res = digits.parse("x".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.error.unwrap().to_string(), "expected digit");
+
+        // the terminator is required after the maximum count
+        let res = digits.parse("1234x".chars());
+        assert_eq!(res.output, None);
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "4x");
+        assert_eq!(error.to_string(), "expected letter");
+
+        // item failed
+        let res = digits.parse("1;".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.error.unwrap().to_string(), "expected letter or digit");
+    }
+
+    #[test]
+    fn terminator_below_minimum() {
+        // the terminator also matches the start of an item
+        let parser = RepeatUntilParser::from(
+            SingleRangeParser::from('a'..='z'),
+            SingleEqualParser::new('x'),
+            2..,
+        );
+
+        let res = parser.parse("xxx;".chars());
+        assert_eq!(res.output, Some((vec!['x', 'x'], 'x')));
+        assert_eq!(res.it.as_str(), ";");
+
+        let res = parser.parse("xyx".chars());
+        assert_eq!(res.output, Some((vec!['x', 'y'], 'x')));
+
+        let res = parser.match_pattern("xxx".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "");
+
+        let res = parser.parse("xx".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "xx");
+    }
+}
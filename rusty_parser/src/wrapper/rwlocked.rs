@@ -0,0 +1,62 @@
+use std::sync::{PoisonError, RwLock};
+
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+
+// A read lock is held while parsing; assigning a new parser (write lock)
+// while another thread is parsing blocks until it is done.
+// A recursive parser must not read the same `RwLock` again: `RwLock::read` may deadlock or panic
+// if the thread already holds the lock, e.g. with a writer waiting.
+// Use `recursive` or `OnceLock` for recursion instead.
+// Parsing does not modify the parser, so a lock poisoned by a panic is still usable.
+
+impl<ParserType, It> Parser<It> for RwLock<ParserType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        self.read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .parse(it)
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .match_pattern(it)
+    }
+}
+impl<ParserType> IntoParser for RwLock<ParserType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::leaf::singleeq::SingleEqualParser;
+    use crate::wrapper::boxed::sync::DynBoxCharsSync;
+
+    #[test]
+    fn assign() {
+        let parser = Arc::new(RwLock::new(DynBoxCharsSync::<(char,)>::default()));
+        parser.write().unwrap().assign(SingleEqualParser::new('a'));
+
+        let res = parser.parse("ab".chars());
+        assert_eq!(res.output, Some(('a',)));
+        assert_eq!(res.it.as_str(), "b");
+
+        parser.write().unwrap().assign(SingleEqualParser::new('b'));
+        let res = parser.parse("ab".chars());
+        assert_eq!(res.output, None);
+    }
+}
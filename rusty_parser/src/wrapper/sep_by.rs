@@ -0,0 +1,462 @@
+use super::tupleutils::concat::AppendTupleToTuple;
+
+use crate::core::error;
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::{self, Parser};
+use crate::core::partial;
+use crate::core::range_copyable::{RangeBound, ToCopyable};
+use crate::core::result::ParseResult;
+use crate::core::tuple::Tuple;
+use crate::wrapper::repeat::RepeatCountType;
+use crate::wrapper::tupleutils::vec::VectorOutputSpecialize;
+
+/// Whether a separator may follow the last element of [`crate::IntoParser::sep_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Trailing {
+    /// The list ends with an element; a separator after it is not consumed.
+    #[default]
+    Forbid,
+    /// A separator after the last element is consumed, if any.
+    Allow,
+    /// Every element, including the last one, must be followed by a separator.
+    Require,
+}
+
+// element or separator, in the order they appear in the input
+enum Piece<Elem, Sep> {
+    Elem(Elem),
+    Sep(Sep),
+}
+
+// Shared loop of `parse` and `match_pattern`;
+// `parse_elem` and `parse_sep` may either parse or just match the pattern,
+// and `push` takes the outputs of the accepted pieces.
+fn sep_by_impl<It, RangeType, Elem, Sep>(
+    range: &RangeType,
+    trailing: Trailing,
+    it: It,
+    parse_elem: impl Fn(It) -> ParseResult<Elem, It>,
+    parse_sep: impl Fn(It) -> ParseResult<Sep, It>,
+    mut push: impl FnMut(Piece<Elem, Sep>),
+) -> ParseResult<(), It>
+where
+    It: InputIteratorTrait,
+    RangeType: RangeBound<RepeatCountType>,
+    Elem: Tuple,
+    Sep: Tuple,
+{
+    let i0 = it.clone();
+    // end of the accepted pieces
+    let mut it = it;
+    let mut count: RepeatCountType = 0;
+    // furthest failure among the pieces
+    let mut error = None;
+    let mut incomplete = None;
+    let mut recovered = Vec::new();
+
+    // failure of the whole list, after a hard error
+    macro_rules! fail {
+        ($res:expr) => {
+            return ParseResult {
+                output: None,
+                it: i0,
+                error,
+                recovered: Vec::new(),
+                committed: $res.committed,
+                incomplete,
+            }
+        };
+    }
+
+    loop {
+        // check reached max count; a trailing separator may still follow
+        if range.contains(&count) && !range.contains(&(count + 1)) {
+            if trailing == Trailing::Allow && count > 0 {
+                let res = parse_sep(it.clone());
+                error = error::merge(error, res.error);
+                incomplete = partial::merge(incomplete, res.incomplete);
+                if let Some(sep) = res.output {
+                    recovered = error::concat(recovered, res.recovered);
+                    push(Piece::Sep(sep));
+                    it = res.it;
+                } else if res.committed {
+                    fail!(res);
+                }
+            }
+            break;
+        }
+
+        // separator before the element
+        let mut cur = it.clone();
+        let mut sep_before = None;
+        let mut pending = Vec::new();
+        if count > 0 && trailing != Trailing::Require {
+            let res = parse_sep(cur);
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
+            match res.output {
+                Some(sep) => {
+                    pending = res.recovered;
+                    sep_before = Some(sep);
+                    cur = res.it;
+                }
+                None if res.committed => fail!(res),
+                None => break,
+            }
+        }
+
+        let res = parse_elem(cur);
+        error = error::merge(error, res.error);
+        incomplete = partial::merge(incomplete, res.incomplete);
+        let elem = match res.output {
+            Some(elem) => elem,
+            None if res.committed => fail!(res),
+            None => {
+                // the separator was trailing
+                if trailing == Trailing::Allow {
+                    if let Some(sep) = sep_before {
+                        recovered = error::concat(recovered, pending);
+                        push(Piece::Sep(sep));
+                        it = res.it;
+                    }
+                }
+                break;
+            }
+        };
+        pending = error::concat(pending, res.recovered);
+        cur = res.it;
+
+        // separator after the element
+        let mut sep_after = None;
+        if trailing == Trailing::Require {
+            let res = parse_sep(cur);
+            error = error::merge(error, res.error);
+            incomplete = partial::merge(incomplete, res.incomplete);
+            match res.output {
+                Some(sep) => {
+                    pending = error::concat(pending, res.recovered);
+                    sep_after = Some(sep);
+                    cur = res.it;
+                }
+                None if res.committed => fail!(res),
+                None => break,
+            }
+        }
+
+        count += 1;
+        recovered = error::concat(recovered, pending);
+        if let Some(sep) = sep_before {
+            push(Piece::Sep(sep));
+        }
+        push(Piece::Elem(elem));
+        if let Some(sep) = sep_after {
+            push(Piece::Sep(sep));
+        }
+        it = cur;
+    }
+
+    if range.contains(&count) {
+        ParseResult {
+            output: Some(()),
+            it,
+            error,
+            recovered,
+            committed: false,
+            incomplete,
+        }
+    } else {
+        ParseResult {
+            output: None,
+            it: i0,
+            error,
+            recovered: Vec::new(),
+            committed: false,
+            incomplete,
+        }
+    }
+}
+
+// repeat the element parser, separated by the separator parser;
+// output of the separators is discarded
+#[derive(Debug, Clone, Copy)]
+pub struct SepByParser<ParserType, SepType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+{
+    parser: ParserType,
+    sep: SepType,
+    range: RangeType,
+    trailing: Trailing,
+}
+
+impl<ParserType, SepType, RangeType> SepByParser<ParserType, SepType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+{
+    pub fn new(parser: ParserType, sep: SepType, range: RangeType) -> Self {
+        Self {
+            parser,
+            sep,
+            range,
+            trailing: Trailing::Forbid,
+        }
+    }
+    pub fn from<RangeType_>(parser: ParserType, sep: SepType, range: RangeType_) -> Self
+    where
+        RangeType_: ToCopyable<Into = RangeType>,
+    {
+        Self::new(parser, sep, range.into())
+    }
+
+    /// Set whether a separator may follow the last element. Default is [`Trailing::Forbid`].
+    pub fn trailing(self, trailing: Trailing) -> Self {
+        Self { trailing, ..self }
+    }
+
+    /// Collect the output of the separators too.
+    ///
+    /// `Output`: `( *<Output of Self>, *<Output of repeated separator> )`
+    pub fn with_separators(self) -> SepByWithParser<ParserType, SepType, RangeType> {
+        SepByWithParser { inner: self }
+    }
+}
+
+impl<ParserType, SepType, RangeType, It> Parser<It> for SepByParser<ParserType, SepType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+    SepType: Parser<It>,
+    <ParserType as Parser<It>>::Output: VectorOutputSpecialize,
+    <<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::Output: Tuple,
+{
+    type Output = <<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let mut output =
+            <<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::new_output();
+        let res = sep_by_impl(
+            &self.range,
+            self.trailing,
+            it,
+            |it| self.parser.parse(it),
+            |it| parser::match_reporting(&self.sep, it),
+            |piece| {
+                if let Piece::Elem(elem) = piece {
+                    elem.push_this_to_output(&mut output);
+                }
+            },
+        );
+        ParseResult {
+            output: res.output.map(|_| output),
+            it: res.it,
+            error: res.error,
+            recovered: res.recovered,
+            committed: res.committed,
+            incomplete: res.incomplete,
+        }
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        sep_by_impl(
+            &self.range,
+            self.trailing,
+            it,
+            |it| self.parser.match_pattern(it),
+            |it| self.sep.match_pattern(it),
+            |_| {},
+        )
+    }
+}
+
+impl<ParserType, SepType, RangeType> IntoParser for SepByParser<ParserType, SepType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+// `SepByParser` which also collects the output of the separators
+#[derive(Debug, Clone, Copy)]
+pub struct SepByWithParser<ParserType, SepType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+{
+    inner: SepByParser<ParserType, SepType, RangeType>,
+}
+
+impl<ParserType, SepType, RangeType> SepByWithParser<ParserType, SepType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+{
+    /// Set whether a separator may follow the last element. Default is [`Trailing::Forbid`].
+    pub fn trailing(self, trailing: Trailing) -> Self {
+        Self {
+            inner: self.inner.trailing(trailing),
+        }
+    }
+}
+
+impl<ParserType, SepType, RangeType, It> Parser<It>
+    for SepByWithParser<ParserType, SepType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+    SepType: Parser<It>,
+    <ParserType as Parser<It>>::Output: VectorOutputSpecialize,
+    <SepType as Parser<It>>::Output: VectorOutputSpecialize,
+    <<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::Output:
+        AppendTupleToTuple<<<SepType as Parser<It>>::Output as VectorOutputSpecialize>::Output>,
+    <<<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::Output as AppendTupleToTuple<
+        <<SepType as Parser<It>>::Output as VectorOutputSpecialize>::Output,
+    >>::Output: Tuple,
+{
+    type Output =
+        <<<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::Output as AppendTupleToTuple<
+            <<SepType as Parser<It>>::Output as VectorOutputSpecialize>::Output,
+        >>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let mut elems =
+            <<ParserType as Parser<It>>::Output as VectorOutputSpecialize>::new_output();
+        let mut seps = <<SepType as Parser<It>>::Output as VectorOutputSpecialize>::new_output();
+        let res = sep_by_impl(
+            &self.inner.range,
+            self.inner.trailing,
+            it,
+            |it| self.inner.parser.parse(it),
+            |it| self.inner.sep.parse(it),
+            |piece| match piece {
+                Piece::Elem(elem) => elem.push_this_to_output(&mut elems),
+                Piece::Sep(sep) => sep.push_this_to_output(&mut seps),
+            },
+        );
+        ParseResult {
+            output: res.output.map(|_| elems.append_back(seps)),
+            it: res.it,
+            error: res.error,
+            recovered: res.recovered,
+            committed: res.committed,
+            incomplete: res.incomplete,
+        }
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        sep_by_impl(
+            &self.inner.range,
+            self.inner.trailing,
+            it,
+            |it| self.inner.parser.match_pattern(it),
+            |it| self.inner.sep.match_pattern(it),
+            |_| {},
+        )
+    }
+}
+
+impl<ParserType, SepType, RangeType> IntoParser for SepByWithParser<ParserType, SepType, RangeType>
+where
+    RangeType: RangeBound<RepeatCountType>,
+{
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::leaf::stringeq::StrEqualParser;
+    use crate::wrapper::or::OrParser;
+
+    #[test]
+    fn forbid() {
+        let digit = SingleRangeParser::from('0'..='9').label("digit");
+        let list = SepByParser::from(digit, ','.into_parser(), 0..);
+
+        let res = list.parse("1,2,3;".chars());
+        assert_eq!(res.output, Some((vec!['1', '2', '3'],)));
+        assert_eq!(res.it.as_str(), ";");
+
+        // trailing separator is left unconsumed
+        let res = list.parse("1,2,;".chars());
+        assert_eq!(res.output, Some((vec!['1', '2'],)));
+        assert_eq!(res.it.as_str(), ",;");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), ";");
+        assert_eq!(error.to_string(), "expected digit");
+
+        let res = list.parse(";".chars());
+        assert_eq!(res.output, Some((vec![],)));
+        assert_eq!(res.it.as_str(), ";");
+
+        // separator without a preceding element is not trailing
+        let res = list.trailing(Trailing::Allow).parse(",1".chars());
+        assert_eq!(res.output, Some((vec![],)));
+        assert_eq!(res.it.as_str(), ",1");
+    }
+
+    #[test]
+    fn allow() {
+        let digit = SingleRangeParser::from('0'..='9');
+        let list = SepByParser::from(digit, ','.into_parser(), 1..).trailing(Trailing::Allow);
+
+        let res = list.parse("1,2,;".chars());
+        assert_eq!(res.output, Some((vec!['1', '2'],)));
+        assert_eq!(res.it.as_str(), ";");
+
+        let res = list.parse("1,2;".chars());
+        assert_eq!(res.output, Some((vec!['1', '2'],)));
+        assert_eq!(res.it.as_str(), ";");
+
+        // trailing separator after the maximum number of elements
+        let list = SepByParser::from(digit, ','.into_parser(), ..=2).trailing(Trailing::Allow);
+        let res = list.parse("1,2,3".chars());
+        assert_eq!(res.output, Some((vec!['1', '2'],)));
+        assert_eq!(res.it.as_str(), "3");
+
+        let res = list.match_pattern("1,2,3".chars());
+        assert_eq!(res.output, Some(()));
+        assert_eq!(res.it.as_str(), "3");
+    }
+
+    #[test]
+    fn require() {
+        let digit = SingleRangeParser::from('0'..='9');
+        let list = SepByParser::from(digit, ';'.into_parser(), 2..).trailing(Trailing::Require);
+
+        let res = list.parse("1;2;3".chars());
+        assert_eq!(res.output, Some((vec!['1', '2'],)));
+        assert_eq!(res.it.as_str(), "3");
+
+        let res = list.parse("1;2".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "1;2");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "");
+        assert_eq!(error.to_string(), "expected ';'");
+    }
+
+    #[test]
+    fn separators() {
+        let digit = SingleRangeParser::from('0'..='9');
+        let op = OrParser::new('+'.into_parser(), '-'.into_parser());
+        let list = SepByParser::from(digit, op, 1..).with_separators();
+
+        let res = list.parse("1+2-3".chars());
+        assert_eq!(res.output, Some((vec!['1', '2', '3'], vec!['+', '-'])));
+
+        let res = list.trailing(Trailing::Allow).parse("1+2-".chars());
+        assert_eq!(res.output, Some((vec!['1', '2'], vec!['+', '-'])));
+        assert_eq!(res.it.as_str(), "");
+
+        // void separators add nothing
+        let list = SepByParser::from(digit, StrEqualParser::new(", "), 1..).with_separators();
+        let res = list.parse("1, 2".chars());
+        assert_eq!(res.output, Some((vec!['1', '2'],)));
+    }
+}
@@ -1,8 +1,10 @@
 use super::tupleutils::concat::AppendTupleToTuple;
 
+use crate::core::error;
 use crate::core::into_parser::IntoParser;
 use crate::core::iterator_bound::InputIteratorTrait;
 use crate::core::parser::Parser;
+use crate::core::partial;
 use crate::core::result::ParseResult;
 use crate::core::tuple::Tuple;
 
@@ -39,18 +41,24 @@ where
                 ParseResult {
                     output: Some(val_a.append_back(val_b)),
                     it: res_b.it,
+                    error: error::merge(res_a.error, res_b.error),
+                    recovered: error::concat(res_a.recovered, res_b.recovered),
+                    committed: res_a.committed || res_b.committed,
+                    incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
                 }
             } else {
                 ParseResult {
                     output: None,
                     it: i0,
+                    error: error::merge(res_a.error, res_b.error),
+                    recovered: Vec::new(),
+                    // failed after cut
+                    committed: res_a.committed || res_b.committed,
+                    incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
                 }
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            res_a.rewind(i0)
         }
     }
 
@@ -63,18 +71,24 @@ where
                 ParseResult {
                     output: Some(()),
                     it: res_b.it,
+                    error: error::merge(res_a.error, res_b.error),
+                    recovered: error::concat(res_a.recovered, res_b.recovered),
+                    committed: res_a.committed || res_b.committed,
+                    incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
                 }
             } else {
                 ParseResult {
                     output: None,
                     it: i0,
+                    error: error::merge(res_a.error, res_b.error),
+                    recovered: Vec::new(),
+                    // failed after cut
+                    committed: res_a.committed || res_b.committed,
+                    incomplete: partial::merge(res_a.incomplete, res_b.incomplete),
                 }
             }
         } else {
-            ParseResult {
-                output: None,
-                it: i0,
-            }
+            res_a.rewind(i0)
         }
     }
 }
@@ -134,4 +148,19 @@ mod test {
         let rest: String = res.it.collect();
         assert_eq!(rest, "a234abcd");
     }
+
+    #[test]
+    fn error_position() {
+        let digit_parser = SingleRangeParser::from('0'..='9');
+        let seq_parser = SeqParser::new(digit_parser, digit_parser);
+
+        let str = "1a34abcd";
+        let res = seq_parser.parse(str.chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "1a34abcd");
+        // error points to where the second parser failed
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "a34abcd");
+        assert_eq!(error.found(), Some('a'));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::core::into_parser::IntoParser;
-use crate::core::parser::Parser;
+use crate::core::parser::{self, Parser};
 use crate::core::result::ParseResult;
+use crate::core::span::SliceInput;
 use crate::InputIteratorTrait;
 
 #[derive(Debug, Clone, Copy)]
@@ -14,31 +15,24 @@ impl<ParserType> StringParser<ParserType> {
     }
 }
 
-impl<'a, ParserType> Parser<std::str::Chars<'a>> for StringParser<ParserType>
+impl<'a, ParserType, It> Parser<It> for StringParser<ParserType>
 where
-    ParserType: Parser<std::str::Chars<'a>>,
+    It: InputIteratorTrait + SliceInput<Slice = &'a str>,
+    ParserType: Parser<It>,
 {
     type Output = (String,);
 
-    fn parse(&self, it: std::str::Chars<'a>) -> ParseResult<Self::Output, std::str::Chars<'a>> {
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let i0 = it.clone();
-        let res = self.parser.match_pattern(it);
+        let res = parser::match_reporting(&self.parser, it);
         if res.output.is_some() {
-            // this is length in bytes
-            let len = i0.as_str().len() - res.it.as_str().len();
-            ParseResult {
-                // and this is byte slice casted to str
-                output: Some((String::from(&i0.as_str()[..len]),)),
-                it: res.it,
-            }
+            let string = String::from(i0.consumed(&res.it));
+            res.map_output(|()| (string,))
         } else {
-            ParseResult {
-                output: None,
-                it: res.it,
-            }
+            res.rewind(i0)
         }
     }
-    fn match_pattern(&self, it: std::str::Chars<'a>) -> ParseResult<(), std::str::Chars<'a>> {
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
         self.parser.match_pattern(it)
     }
 }
@@ -50,6 +44,88 @@ impl<ParserType> IntoParser for StringParser<ParserType> {
     }
 }
 
+// zero-copy version of `StringParser`; borrows the matched range from the input
+#[derive(Debug, Clone, Copy)]
+pub struct StrParser<ParserType> {
+    parser: ParserType,
+}
+
+impl<ParserType> StrParser<ParserType> {
+    pub fn new(parser: ParserType) -> Self {
+        Self { parser }
+    }
+}
+
+impl<'a, ParserType, It> Parser<It> for StrParser<ParserType>
+where
+    It: InputIteratorTrait + SliceInput<Slice = &'a str>,
+    ParserType: Parser<It>,
+{
+    type Output = (&'a str,);
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let res = parser::match_reporting(&self.parser, it);
+        if res.output.is_some() {
+            let slice = i0.consumed(&res.it);
+            res.map_output(|()| (slice,))
+        } else {
+            res.rewind(i0)
+        }
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<ParserType> IntoParser for StrParser<ParserType> {
+    type Into = StrParser<ParserType>;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+// zero-copy version of `VecParser`; borrows the matched range from the input buffer
+#[derive(Debug, Clone, Copy)]
+pub struct SliceRefParser<ParserType> {
+    parser: ParserType,
+}
+
+impl<ParserType> SliceRefParser<ParserType> {
+    pub fn new(parser: ParserType) -> Self {
+        Self { parser }
+    }
+}
+
+impl<It, ParserType> Parser<It> for SliceRefParser<ParserType>
+where
+    It: InputIteratorTrait + SliceInput,
+    ParserType: Parser<It>,
+{
+    type Output = (<It as SliceInput>::Slice,);
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let res = parser::match_reporting(&self.parser, it);
+        if res.output.is_some() {
+            let slice = i0.consumed(&res.it);
+            res.map_output(|()| (slice,))
+        } else {
+            res.rewind(i0)
+        }
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<ParserType> IntoParser for SliceRefParser<ParserType> {
+    type Into = SliceRefParser<ParserType>;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct VecParser<ParserType> {
     parser: ParserType,
@@ -69,18 +145,13 @@ where
 
     fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
         let i0 = it.clone();
-        let res = self.parser.match_pattern(it);
+        let res = parser::match_reporting(&self.parser, it);
         if res.output.is_some() {
             let len = i0.len() - res.it.len();
-            ParseResult {
-                output: Some((i0.take(len).collect(),)),
-                it: res.it,
-            }
+            let items = i0.take(len).collect();
+            res.map_output(|()| (items,))
         } else {
-            ParseResult {
-                output: None,
-                it: res.it,
-            }
+            res.rewind(i0)
         }
     }
     fn match_pattern(&self, it: It) -> ParseResult<(), It> {
@@ -98,6 +169,7 @@ impl<ParserType> IntoParser for VecParser<ParserType> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::core::located::Located;
     use crate::{leaf::singlerange::SingleRangeParser, wrapper::seq::SeqParser};
 
     #[test]
@@ -122,4 +194,55 @@ mod test {
         assert_eq!(res.output, None);
         assert_eq!(res.it.collect::<String>(), "ab3456abcd");
     }
+    #[test]
+    fn located() {
+        let digit_parser = SingleRangeParser::from('0'..='9');
+        let digit_parser = SeqParser::new(digit_parser, digit_parser);
+        let digit_parser = StringParser::new(digit_parser);
+
+        let res = digit_parser.parse(Located::new("123456abcd".chars()));
+        assert_eq!(res.output.unwrap(), ("12".to_string(),));
+        assert_eq!(res.it.as_str(), "3456abcd");
+    }
+
+    #[test]
+    fn str_borrowed() {
+        let digit_parser = SingleRangeParser::from('0'..='9');
+        let digit_parser = SeqParser::new(digit_parser, digit_parser);
+        let digit_parser = StrParser::new(digit_parser);
+
+        let str = "123456abcd";
+        let res = digit_parser.parse(str.chars());
+        let (digits,) = res.output.unwrap();
+        assert_eq!(digits, "12");
+        // points into the input, not a copy
+        assert_eq!(digits.as_ptr(), str.as_ptr());
+        assert_eq!(res.it.as_str(), "3456abcd");
+
+        let res = digit_parser.parse("ab3456abcd".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "ab3456abcd");
+
+        let res = digit_parser.parse(Located::new(str.chars()));
+        assert_eq!(res.output.unwrap(), ("12",));
+        assert_eq!(res.it.as_str(), "3456abcd");
+    }
+
+    #[test]
+    fn slice_ref() {
+        let digit_parser = SingleRangeParser::from(&b'0'..=&b'9');
+        let digit_parser = SeqParser::new(digit_parser, digit_parser);
+        let digit_parser = SliceRefParser::new(digit_parser);
+
+        let input = b"123456abcd";
+        let res = digit_parser.parse(input.iter());
+        let (digits,) = res.output.unwrap();
+        assert_eq!(digits, b"12");
+        assert_eq!(digits.as_ptr(), input.as_ptr());
+        assert_eq!(res.it.as_slice(), b"3456abcd");
+
+        let res = digit_parser.parse(input[6..].iter());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_slice(), b"abcd");
+    }
 }
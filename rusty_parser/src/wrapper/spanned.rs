@@ -0,0 +1,124 @@
+use super::tupleutils::concat::AppendTupleToTuple;
+
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+use crate::core::span::{Span, SpanInput};
+use crate::core::tuple::Tuple;
+
+#[derive(Debug, Clone, Copy)]
+pub struct SpannedParser<ParserType> {
+    parser: ParserType,
+}
+
+impl<ParserType> SpannedParser<ParserType> {
+    pub fn new(parser: ParserType) -> Self {
+        Self { parser }
+    }
+}
+
+impl<ParserType, It> Parser<It> for SpannedParser<ParserType>
+where
+    It: InputIteratorTrait + SpanInput,
+    ParserType: Parser<It>,
+    <ParserType as Parser<It>>::Output: AppendTupleToTuple<(Span,)>,
+    <<ParserType as Parser<It>>::Output as AppendTupleToTuple<(Span,)>>::Output: Tuple,
+{
+    type Output = <<ParserType as Parser<It>>::Output as AppendTupleToTuple<(Span,)>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let res = self.parser.parse(it);
+        let span = i0.span_to(&res.it);
+        res.map_output(|val| val.append_back((span,)))
+    }
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        self.parser.match_pattern(it)
+    }
+}
+
+impl<ParserType> IntoParser for SpannedParser<ParserType> {
+    type Into = Self;
+
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::located::Located;
+    use crate::core::read::ReadBytes;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::repeat::RepeatParser;
+    use crate::wrapper::seq::SeqParser;
+    use crate::wrapper::void::VoidParser;
+
+    #[test]
+    fn chars() {
+        let digits = RepeatParser::from(SingleRangeParser::from('0'..='9'), 1..);
+        let parser = SeqParser::new(
+            VoidParser::new(SingleRangeParser::from('a'..='z')),
+            SpannedParser::new(digits),
+        );
+
+        let input = "a123b";
+        let res = parser.parse(Located::new(input.chars()));
+        let (digits, span) = res.output.unwrap();
+        assert_eq!(digits, vec!['1', '2', '3']);
+        assert_eq!(span.range(), Some(1..4));
+        assert_eq!(&input[span.range_in(input)], "123");
+    }
+
+    #[test]
+    fn bytes() {
+        let parser = SpannedParser::new(VoidParser::new(RepeatParser::from(
+            SingleRangeParser::from(1..=3),
+            0..,
+        )));
+
+        let input = [1u8, 2, 3, 4, 5];
+        let mut it = Located::new(input.iter().copied());
+        it.next();
+        let res = parser.parse(it);
+        let (span,) = res.output.unwrap();
+        assert_eq!(span.range(), Some(1..3));
+        assert_eq!(span.len(), 2);
+
+        let res = parser.parse(ReadBytes::new(&input[3..]));
+        let (span,) = res.output.unwrap();
+        assert!(span.is_empty());
+        assert_eq!(span.start(), Some(0));
+    }
+
+    #[test]
+    fn from_end() {
+        let digits = RepeatParser::from(SingleRangeParser::from('0'..='9'), 1..);
+        let parser = SeqParser::new(
+            VoidParser::new(SingleRangeParser::from('a'..='z')),
+            SpannedParser::new(VoidParser::new(digits)),
+        );
+
+        // counted from the end of the input, resolved against it
+        let input = "a123b";
+        let res = parser.parse(input.chars());
+        let (span,) = res.output.unwrap();
+        assert_eq!(span.range(), None);
+        assert_eq!(span.range_in(input), 1..4);
+        assert_eq!(res.it.collect::<String>(), "b");
+
+        let parser = SpannedParser::new(VoidParser::new(RepeatParser::from(
+            SingleRangeParser::from(1..=3),
+            0..,
+        )));
+        let input = [1u8, 2, 3, 4, 5];
+        let mut it = input.iter().copied();
+        it.next();
+        let (span,) = parser.parse(it.clone()).output.unwrap();
+        assert_eq!(span.range_in(&input), 1..3);
+        let (span,) = parser.parse(input.iter().cloned()).output.unwrap();
+        assert_eq!(span.range_in(&input), 0..3);
+    }
+}
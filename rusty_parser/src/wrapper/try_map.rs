@@ -0,0 +1,177 @@
+use std::error::Error;
+
+use crate::core::error::ParseError;
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+use crate::core::tuple::Tuple;
+use crate::wrapper::tupleutils::unpack::TupleUnpack;
+
+/// Return type of the closure passed to [`crate::IntoParser::try_map`].
+pub trait TryMapOutput {
+    type Output;
+
+    /// `Err` if the match should be rejected, with the error to report.
+    fn into_result(self) -> Result<Self::Output, Option<Box<dyn Error + Send + Sync>>>;
+}
+
+impl<T, E> TryMapOutput for Result<T, E>
+where
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Output = T;
+
+    fn into_result(self) -> Result<Self::Output, Option<Box<dyn Error + Send + Sync>>> {
+        self.map_err(|err| Some(err.into()))
+    }
+}
+
+impl<T> TryMapOutput for Option<T> {
+    type Output = T;
+
+    fn into_result(self) -> Result<Self::Output, Option<Box<dyn Error + Send + Sync>>> {
+        self.ok_or(None)
+    }
+}
+
+// Callback takes Parser's output as input;
+// Ok value returned from the callback would be new value of the parser,
+// Err rejects the match and fails at the start position
+
+#[derive(Debug, Clone, Copy)]
+pub struct TryMapParser<ParserType, ClosureType> {
+    parser: ParserType,
+    callback: ClosureType,
+}
+
+impl<ParserType, ClosureType> TryMapParser<ParserType, ClosureType> {
+    pub fn new(parser: ParserType, callback: ClosureType) -> Self {
+        Self { parser, callback }
+    }
+}
+
+impl<ParserType, ClosureType, It> Parser<It> for TryMapParser<ParserType, ClosureType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+    ClosureType: TupleUnpack<<ParserType as Parser<It>>::Output>,
+    ClosureType::Output: TryMapOutput,
+    <ParserType as Parser<It>>::Output: Tuple,
+{
+    type Output = (<ClosureType::Output as TryMapOutput>::Output,);
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let res = self.parser.parse(it);
+        if let Some(val) = res.output {
+            match (self.callback).map(val).into_result() {
+                Ok(callback_res) => ParseResult {
+                    output: Some((callback_res,)),
+                    it: res.it,
+                    error: res.error,
+                    recovered: res.recovered,
+                    committed: res.committed,
+                    incomplete: res.incomplete,
+                },
+                // the match itself was rejected;
+                // report the closure's error instead of anything the inner parser reached
+                Err(source) => {
+                    let error = match source {
+                        Some(source) => ParseError::rejected(i0.clone(), source),
+                        None => ParseError::new(i0.clone()),
+                    };
+                    ParseResult {
+                        committed: res.committed,
+                        incomplete: res.incomplete,
+                        ..ParseResult::failure(i0, error)
+                    }
+                }
+            }
+        } else {
+            res.rewind(i0)
+        }
+    }
+}
+
+impl<ParserType, ClosureType> IntoParser for TryMapParser<ParserType, ClosureType> {
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::repeat::RepeatParser;
+
+    #[test]
+    fn result() {
+        let digits = RepeatParser::from(SingleRangeParser::from('0'..='9').label("digit"), 1..);
+        let byte_parser = TryMapParser::new(digits, |digits: Vec<char>| {
+            digits.into_iter().collect::<String>().parse::<u8>()
+        });
+
+        let res = byte_parser.parse("123abc".chars());
+        assert_eq!(res.output, Some((123,)));
+        assert_eq!(res.it.as_str(), "abc");
+
+        let res = byte_parser.parse("1234abc".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "1234abc");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "1234abc");
+        assert_eq!(error.to_string(), "number too large to fit in target type");
+        // the closure's error is kept with its type
+        let source = error.source.unwrap();
+        let source = source.downcast_ref::<std::num::ParseIntError>().unwrap();
+        assert_eq!(source.kind(), &std::num::IntErrorKind::PosOverflow);
+
+        // failure of the inner parser is kept
+        let res = byte_parser.parse("abc".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.error.unwrap().to_string(), "expected digit");
+    }
+
+    #[test]
+    fn message() {
+        let digit = SingleRangeParser::from('0'..='9');
+        let parser = TryMapParser::new(digit, |ch: char| {
+            if ch == '0' {
+                Err("leading zero")
+            } else {
+                Ok(ch)
+            }
+        });
+
+        let error = parser.parse("0".chars()).error.unwrap();
+        assert_eq!(error.to_string(), "leading zero");
+        assert_eq!(error.source.unwrap().to_string(), "leading zero");
+    }
+
+    // `is_multiple_of` is newer than the toolchains this crate supports
+    #[allow(clippy::manual_is_multiple_of)]
+    #[test]
+    fn option() {
+        let digit = SingleRangeParser::from('0'..='9');
+        let even_parser = TryMapParser::new(digit, |ch: char| {
+            let digit = ch.to_digit(10).unwrap();
+            (digit % 2 == 0).then_some(digit)
+        });
+
+        let res = even_parser.parse("4".chars());
+        assert_eq!(res.output, Some((4,)));
+
+        let res = even_parser.parse("3".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "3");
+        let error = res.error.unwrap();
+        assert_eq!(error.to_string(), "unexpected input");
+        assert!(error.source.is_none());
+
+        let res = even_parser.match_pattern("3".chars());
+        assert_eq!(res.output, None);
+    }
+}
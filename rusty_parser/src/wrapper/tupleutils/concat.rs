@@ -1,7 +1,6 @@
 /// Concatenate Tuple
 /// Maximum Tuple Size: 12
 /// TODO: any elegant way to merge tuple?
-
 pub trait AppendTupleToTuple<Tup> {
     type Output;
     fn append_back(self, val: Tup) -> Self::Output;
@@ -0,0 +1,121 @@
+use crate::core::error::ParseError;
+use crate::core::into_parser::IntoParser;
+use crate::core::iterator_bound::InputIteratorTrait;
+use crate::core::parser::Parser;
+use crate::core::result::ParseResult;
+use crate::wrapper::tupleutils::unpack::TupleUnpackRef;
+
+// Predicate takes references to Parser's output;
+// if it returns false, the match is rejected and fails at the start position,
+// reporting an error without expectation there instead of anything the inner parser reached;
+// name what is expected with `label`
+
+#[derive(Debug, Clone, Copy)]
+pub struct VerifyParser<ParserType, ClosureType> {
+    parser: ParserType,
+    predicate: ClosureType,
+}
+
+impl<ParserType, ClosureType> VerifyParser<ParserType, ClosureType> {
+    pub fn new(parser: ParserType, predicate: ClosureType) -> Self {
+        Self { parser, predicate }
+    }
+}
+
+impl<ParserType, ClosureType, It> Parser<It> for VerifyParser<ParserType, ClosureType>
+where
+    It: InputIteratorTrait,
+    ParserType: Parser<It>,
+    ClosureType: TupleUnpackRef<<ParserType as Parser<It>>::Output, Output = bool>,
+{
+    type Output = <ParserType as Parser<It>>::Output;
+
+    fn parse(&self, it: It) -> ParseResult<Self::Output, It> {
+        let i0 = it.clone();
+        let res = self.parser.parse(it);
+        match res.output {
+            Some(val) if !self.predicate.map_ref(&val) => ParseResult {
+                committed: res.committed,
+                incomplete: res.incomplete,
+                ..ParseResult::failure(i0.clone(), ParseError::new(i0))
+            },
+            output => ParseResult { output, ..res },
+        }
+    }
+
+    // the predicate needs the output; a rejection builds no error here
+    fn match_pattern(&self, it: It) -> ParseResult<(), It> {
+        let i0 = it.clone();
+        let res = self.parser.parse(it);
+        match res.output {
+            Some(val) if !self.predicate.map_ref(&val) => ParseResult {
+                committed: res.committed,
+                incomplete: res.incomplete,
+                ..ParseResult::unmatched(i0)
+            },
+            output => ParseResult { output, ..res }.map_output(|_| ()),
+        }
+    }
+}
+
+impl<ParserType, ClosureType> IntoParser for VerifyParser<ParserType, ClosureType> {
+    type Into = Self;
+    fn into_parser(self) -> Self::Into {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::leaf::singlerange::SingleRangeParser;
+    use crate::wrapper::label::LabelParser;
+    use crate::wrapper::or::OrParser;
+    use crate::wrapper::repeat::RepeatParser;
+    use crate::wrapper::slice::StringParser;
+
+    #[test]
+    fn success1() {
+        let ident = StringParser::new(RepeatParser::from(SingleRangeParser::from('a'..='z'), 1..));
+        let not_keyword = VerifyParser::new(ident, |ident: &String| ident != "let");
+
+        let res = not_keyword.parse("name = 1".chars());
+        assert_eq!(res.output, Some(("name".to_string(),)));
+        assert_eq!(res.it.as_str(), " = 1");
+
+        let res = not_keyword.parse("let = 1".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "let = 1");
+        // the rejection, not where `ident` tried to read further
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "let = 1");
+        assert_eq!(error.to_string(), "unexpected input");
+
+        // named with a label
+        let not_keyword = LabelParser::new(not_keyword, "identifier".into());
+        let res = not_keyword.parse("let = 1".chars());
+        assert_eq!(res.error.unwrap().to_string(), "expected identifier");
+
+        let res = not_keyword.match_pattern("let".chars());
+        assert_eq!(res.output, None);
+        let res = not_keyword.match_pattern("lets".chars());
+        assert_eq!(res.output, Some(()));
+    }
+
+    #[test]
+    fn under_or() {
+        let nonzero = VerifyParser::new(SingleRangeParser::from('0'..='9'), |ch: &char| *ch != '0');
+        let nonzero = LabelParser::new(nonzero, "nonzero digit".into());
+        let parser = OrParser::new(nonzero, 'x'.into_parser());
+
+        let res = parser.parse("0".chars());
+        assert_eq!(res.output, None);
+        assert_eq!(res.it.as_str(), "0");
+        let error = res.error.unwrap();
+        assert_eq!(error.it.as_str(), "0");
+        assert_eq!(error.to_string(), "expected nonzero digit or 'x'");
+
+        let res = parser.parse("1".chars());
+        assert_eq!(res.output, Some(('1',)));
+    }
+}